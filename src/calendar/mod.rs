@@ -0,0 +1,93 @@
+mod period;
+
+use crate::types::SchoolScheduleItem;
+use chrono::{Datelike, NaiveDate, Weekday};
+use std::collections::BTreeMap;
+
+pub use period::{Period, PeriodAnalyzer, PeriodKind, PeriodRule};
+
+/// 학사일정 기반 수업일 계산
+///
+/// 주말과 수업공제일(휴업일, 공휴일)을 제외한 날을 수업일로 본다.
+/// 학사일정이 없는 날은 평일이면 수업일로 취급한다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use neis_client::calendar::SchoolCalendar;
+/// use neis_client::types::SchoolScheduleItem;
+///
+/// fn print_next_school_day(items: Vec<SchoolScheduleItem>, today: NaiveDate) {
+///     let calendar = SchoolCalendar::new(items);
+///     if !calendar.is_school_day(today) {
+///         println!("다음 수업일: {:?}", calendar.next_school_day(today));
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SchoolCalendar {
+    days: BTreeMap<NaiveDate, Vec<SchoolScheduleItem>>,
+}
+
+impl SchoolCalendar {
+    pub fn new(items: Vec<SchoolScheduleItem>) -> Self {
+        let mut days: BTreeMap<NaiveDate, Vec<SchoolScheduleItem>> = BTreeMap::new();
+        for item in items {
+            if let Some(date) = item.date() {
+                days.entry(date).or_default().push(item);
+            } else {
+                tracing::warn!(AA_YMD = item.AA_YMD, "invalid schedule date");
+            }
+        }
+        Self { days }
+    }
+
+    /// 해당 일자의 학사일정
+    pub fn events_on(&self, date: NaiveDate) -> &[SchoolScheduleItem] {
+        self.days.get(&date).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// 학사일정이 있는 첫날과 마지막날
+    pub fn range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        let first = self.days.keys().next()?;
+        let last = self.days.keys().next_back()?;
+        Some((*first, *last))
+    }
+
+    pub fn is_school_day(&self, date: NaiveDate) -> bool {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            return false;
+        }
+        self.events_on(date)
+            .iter()
+            .all(|item| item.day_kind().has_classes())
+    }
+
+    /// `from` 부터 `to` 까지(양끝 포함)의 수업일수
+    pub fn count_school_days(&self, from: NaiveDate, to: NaiveDate) -> usize {
+        from.iter_days()
+            .take_while(|date| *date <= to)
+            .filter(|date| self.is_school_day(*date))
+            .count()
+    }
+
+    /// `date` 다음의 첫 수업일
+    pub fn next_school_day(&self, date: NaiveDate) -> Option<NaiveDate> {
+        date.iter_days()
+            .skip(1)
+            .find(|date| self.is_school_day(*date))
+    }
+}
+
+impl From<Vec<SchoolScheduleItem>> for SchoolCalendar {
+    fn from(items: Vec<SchoolScheduleItem>) -> Self {
+        Self::new(items)
+    }
+}
+
+impl FromIterator<SchoolScheduleItem> for SchoolCalendar {
+    fn from_iter<I: IntoIterator<Item = SchoolScheduleItem>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,244 @@
+use crate::types::SchoolScheduleItem;
+use chrono::NaiveDate;
+
+/// 학사일정에서 추출한 기간 종류
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PeriodKind {
+    /// 방학
+    Vacation,
+    /// 중간고사
+    MidtermExam,
+    /// 기말고사
+    FinalExam,
+    /// 학기
+    Semester,
+}
+
+/// 시작일과 종료일(포함)을 가진 기간
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Period {
+    pub kind: PeriodKind,
+    /// 기간을 이루는 첫 행사명
+    /// Example: 1학기 중간고사
+    pub name: String,
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl Period {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+
+    /// 기간의 일수(양끝 포함)
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).num_days() + 1
+    }
+}
+
+/// 행사명 키워드로 기간 종류를 판별하는 규칙
+#[derive(Debug, Clone)]
+pub struct PeriodRule {
+    pub kind: PeriodKind,
+    /// 행사명에 하나라도 포함되면 해당
+    pub keywords: Vec<String>,
+    /// 행사명에 하나라도 포함되면 제외
+    pub excludes: Vec<String>,
+}
+
+impl PeriodRule {
+    pub fn new(kind: PeriodKind, keywords: &[&str]) -> Self {
+        Self {
+            kind,
+            keywords: keywords.iter().map(|s| s.to_string()).collect(),
+            excludes: Vec::new(),
+        }
+    }
+
+    pub fn exclude(mut self, keywords: &[&str]) -> Self {
+        self.excludes.extend(keywords.iter().map(|s| s.to_string()));
+        self
+    }
+
+    pub fn matches(&self, event_name: &str) -> bool {
+        self.keywords
+            .iter()
+            .any(|k| event_name.contains(k.as_str()))
+            && !self
+                .excludes
+                .iter()
+                .any(|k| event_name.contains(k.as_str()))
+    }
+}
+
+/// 학사일정 행사를 방학, 시험, 학기 기간으로 묶는다.
+///
+/// 키워드 규칙에 해당하는 행사는 `max_gap_days` 이내로 이어지면 하나의 기간이 된다.
+/// 학기는 개학식 같은 시작 행사부터 방학식 같은 종료 행사까지이며,
+/// 종료 행사와 다음 시작 행사 사이는 방학으로 본다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::calendar::{PeriodAnalyzer, PeriodKind};
+/// use neis_client::types::SchoolScheduleItem;
+///
+/// fn print_vacations(items: &[SchoolScheduleItem]) {
+///     for period in PeriodAnalyzer::default().analyze(items) {
+///         if period.kind == PeriodKind::Vacation {
+///             println!("{} ~ {} ({}일)", period.start, period.end, period.days());
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct PeriodAnalyzer {
+    rules: Vec<PeriodRule>,
+    semester_starts: Vec<String>,
+    semester_ends: Vec<String>,
+    max_gap_days: i64,
+}
+
+impl Default for PeriodAnalyzer {
+    fn default() -> Self {
+        Self::new()
+            .rule(PeriodRule::new(
+                PeriodKind::MidtermExam,
+                &["중간고사", "1차지필", "1회고사"],
+            ))
+            .rule(PeriodRule::new(
+                PeriodKind::FinalExam,
+                &["기말고사", "2차지필", "2회고사"],
+            ))
+            .rule(PeriodRule::new(PeriodKind::Vacation, &["방학"]).exclude(&["방학식"]))
+            .semester_markers(&["개학식", "입학식"], &["방학식", "종업식", "졸업식"])
+    }
+}
+
+impl PeriodAnalyzer {
+    /// 규칙이 없는 빈 분석기
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            semester_starts: Vec::new(),
+            semester_ends: Vec::new(),
+            max_gap_days: 3, // 금요일 ~ 월요일
+        }
+    }
+
+    pub fn rule(mut self, rule: PeriodRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// 학기 시작, 종료 행사명 키워드
+    pub fn semester_markers(mut self, starts: &[&str], ends: &[&str]) -> Self {
+        self.semester_starts = starts.iter().map(|s| s.to_string()).collect();
+        self.semester_ends = ends.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    /// 같은 기간으로 묶을 행사 사이의 최대 일수 차이
+    pub fn max_gap_days(mut self, days: i64) -> Self {
+        self.max_gap_days = days;
+        self
+    }
+
+    pub fn analyze(&self, items: &[SchoolScheduleItem]) -> Vec<Period> {
+        let mut events: Vec<(NaiveDate, &str)> = items
+            .iter()
+            .filter_map(|item| Some((item.date()?, item.EVENT_NM.trim())))
+            .collect();
+        events.sort();
+        events.dedup();
+
+        let mut periods = Vec::new();
+        for rule in &self.rules {
+            let matched = events.iter().filter(|(_, name)| rule.matches(name));
+            periods.extend(self.group(rule.kind, matched));
+        }
+        periods.extend(self.semesters(&events));
+
+        // 키워드 규칙과 학기 구분 행사로 찾은 방학이 겹치면 합침
+        let (mut vacations, mut periods): (Vec<_>, Vec<_>) = periods
+            .into_iter()
+            .partition(|p| p.kind == PeriodKind::Vacation);
+        vacations.sort_by_key(|p| p.start);
+        let mut merged: Vec<Period> = Vec::new();
+        for vacation in vacations {
+            match merged.last_mut() {
+                Some(last) if (vacation.start - last.end).num_days() <= self.max_gap_days => {
+                    last.end = last.end.max(vacation.end);
+                }
+                _ => merged.push(vacation),
+            }
+        }
+        periods.extend(merged);
+
+        periods.sort_by_key(|p| (p.start, p.kind));
+        periods
+    }
+
+    fn group<'a>(
+        &self,
+        kind: PeriodKind,
+        events: impl Iterator<Item = &'a (NaiveDate, &'a str)>,
+    ) -> Vec<Period> {
+        let mut periods: Vec<Period> = Vec::new();
+        for (date, name) in events {
+            match periods.last_mut() {
+                Some(last) if (*date - last.end).num_days() <= self.max_gap_days => {
+                    last.end = *date;
+                }
+                _ => periods.push(Period {
+                    kind,
+                    name: name.to_string(),
+                    start: *date,
+                    end: *date,
+                }),
+            }
+        }
+        periods
+    }
+
+    fn semesters(&self, events: &[(NaiveDate, &str)]) -> Vec<Period> {
+        let is_start = |name: &str| {
+            self.semester_starts
+                .iter()
+                .any(|k| name.contains(k.as_str()))
+        };
+        let is_end = |name: &str| self.semester_ends.iter().any(|k| name.contains(k.as_str()));
+
+        let mut periods = Vec::new();
+        let mut open: Option<(NaiveDate, &str)> = None;
+        let mut closed: Option<(NaiveDate, &str)> = None;
+        for (date, name) in events {
+            if is_start(name) && open.is_none() {
+                if let Some((end, end_name)) = closed.take()
+                    && let (Some(start), Some(until)) = (end.succ_opt(), date.pred_opt())
+                    && start <= until
+                {
+                    periods.push(Period {
+                        kind: PeriodKind::Vacation,
+                        name: end_name.trim_end_matches('식').to_string(),
+                        start,
+                        end: until,
+                    });
+                }
+                open = Some((*date, name));
+            } else if is_end(name) {
+                if let Some((start, start_name)) = open.take() {
+                    periods.push(Period {
+                        kind: PeriodKind::Semester,
+                        name: start_name.to_string(),
+                        start,
+                        end: *date,
+                    });
+                }
+                // 시작 행사가 조회 범위 이전에 있었던 경우에도 방학은 계산
+                closed = Some((*date, name));
+            }
+        }
+        periods
+    }
+}
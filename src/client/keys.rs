@@ -0,0 +1,181 @@
+use crate::error::Error;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 인증키 한도 초과
+pub(crate) const QUOTA_EXCEEDED: &str = "ERROR-337";
+/// 등록되지 않은 인증키
+pub(crate) const INVALID_KEY: &str = "ERROR-290";
+/// 사용이 제한된 인증키
+pub(crate) const SUSPENDED: &str = "INFO-300";
+/// 인증키가 없거나 형식이 잘못됨
+pub(crate) const MISSING_KEY: &str = "INFO-100";
+
+/// 인증키 확인 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KeyStatus {
+    Valid,
+    /// 등록되지 않은 인증키
+    Invalid,
+    /// 관리자에 의해 사용이 제한됨
+    Suspended,
+    /// 하루 한도 초과
+    QuotaExceeded,
+    /// 그 밖의 결과 코드로 거절됨
+    Rejected {
+        code: String,
+        message: String,
+    },
+    /// 확인할 인증키가 없거나 빈 문자열
+    NoKey,
+    /// 연결 실패로 확인하지 못함
+    NetworkError(String),
+    /// 응답을 해석하지 못해 확인하지 못함
+    MalformedResponse(String),
+}
+
+/// 인증키를 고르는 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum KeyRotation {
+    /// 차례대로
+    #[default]
+    RoundRobin,
+    /// 요청 수가 가장 적은 키
+    LeastUsed,
+}
+
+/// 인증키별 사용 현황
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyUsage {
+    pub key: String,
+    /// 보낸 요청 수
+    pub requests: u64,
+    /// 사용을 멈추게 한 결과 코드
+    ///
+    /// 한도 초과는 한국 표준시로 다음 날 자동으로 풀린다.
+    pub disabled: Option<String>,
+}
+
+#[derive(Debug)]
+pub(crate) struct KeyPool {
+    rotation: KeyRotation,
+    state: Mutex<PoolState>,
+}
+
+#[derive(Debug)]
+struct PoolState {
+    usage: Vec<KeyUsage>,
+    // 한도 초과로 멈춘 날(한국 표준시), 다음 날 다시 쓴다
+    quota_days: Vec<Option<u64>>,
+    next: usize,
+    // 모든 키가 멈췄을 때 돌려줄 마지막 오류
+    last_error: Option<(String, String)>,
+}
+
+impl KeyPool {
+    /// 같은 키는 처음 한 번만 넣는다.
+    pub fn new<S: AsRef<str>>(keys: &[S], rotation: KeyRotation) -> Self {
+        let mut usage: Vec<KeyUsage> = Vec::with_capacity(keys.len());
+        for key in keys {
+            let key = key.as_ref();
+            if usage.iter().all(|usage| usage.key != key) {
+                usage.push(KeyUsage {
+                    key: key.to_owned(),
+                    requests: 0,
+                    disabled: None,
+                });
+            }
+        }
+
+        Self {
+            rotation,
+            state: Mutex::new(PoolState {
+                quota_days: vec![None; usage.len()],
+                usage,
+                next: 0,
+                last_error: None,
+            }),
+        }
+    }
+
+    /// 요청에 쓸 키, 요청 수를 하나 늘린다.
+    pub fn next(&self) -> Result<String, Error> {
+        let mut state = self.state.lock().unwrap();
+        state.release_quota(kst_day());
+        let len = state.usage.len();
+        let index = match self.rotation {
+            KeyRotation::RoundRobin => (0..len)
+                .map(|i| (state.next + i) % len)
+                .find(|i| state.usage[*i].disabled.is_none()),
+            KeyRotation::LeastUsed => (0..len)
+                .filter(|i| state.usage[*i].disabled.is_none())
+                .min_by_key(|i| state.usage[*i].requests),
+        };
+
+        let Some(index) = index else {
+            return Err(match &state.last_error {
+                Some((code, message)) => Error::Api {
+                    code: code.clone(),
+                    message: message.clone(),
+                },
+                None => Error::new_unknown("no api key"),
+            });
+        };
+
+        state.next = (index + 1) % len;
+        let usage = &mut state.usage[index];
+        usage.requests += 1;
+        Ok(usage.key.clone())
+    }
+
+    /// 한도 초과나 잘못된 키로 응답받은 키를 더 이상 쓰지 않는다.
+    pub fn disable(&self, key: &str, code: &str, message: &str) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(index) = state.usage.iter().position(|usage| usage.key == key) {
+            state.usage[index].disabled = Some(code.to_owned());
+            state.quota_days[index] = (code == QUOTA_EXCEEDED).then(kst_day);
+        }
+        state.last_error = Some((code.to_owned(), message.to_owned()));
+    }
+
+    pub fn enable_all(&self) {
+        let mut state = self.state.lock().unwrap();
+        for usage in &mut state.usage {
+            usage.disabled = None;
+        }
+        state.quota_days.fill(None);
+        state.last_error = None;
+    }
+
+    pub fn first(&self) -> Option<String> {
+        let state = self.state.lock().unwrap();
+        state.usage.first().map(|usage| usage.key.clone())
+    }
+
+    pub fn usage(&self) -> Vec<KeyUsage> {
+        let mut state = self.state.lock().unwrap();
+        state.release_quota(kst_day());
+        state.usage.clone()
+    }
+}
+
+impl PoolState {
+    /// 날이 바뀌어 한도가 초기화된 키를 다시 쓴다.
+    fn release_quota(&mut self, today: u64) {
+        for (usage, day) in self.usage.iter_mut().zip(&mut self.quota_days) {
+            if day.is_some_and(|day| day < today) {
+                usage.disabled = None;
+                *day = None;
+            }
+        }
+    }
+}
+
+/// 한국 표준시 기준 1970-01-01 부터 지난 날 수
+fn kst_day() -> u64 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    (secs + 9 * 60 * 60) / (24 * 60 * 60)
+}
@@ -1,7 +1,11 @@
+mod keys;
 mod response;
 
 use crate::error::Error;
+use crate::timetable::{TimetableItem, TimetableKind, TimetableQuery};
 use crate::types::*;
+use futures_util::future::{BoxFuture, FutureExt, Shared};
+use futures_util::{StreamExt, stream};
 use http_body_util::{BodyExt, Empty};
 use hyper::body::Bytes;
 use hyper_tls::HttpsConnector;
@@ -9,21 +13,217 @@ use hyper_util::{
     client::legacy::{Client, connect::HttpConnector},
     rt::TokioExecutor,
 };
-use response::{ExtractFromResponse, ResponseBody};
+use keys::{INVALID_KEY, KeyPool, MISSING_KEY, QUOTA_EXCEEDED, SUSPENDED};
+pub use keys::{KeyRotation, KeyStatus, KeyUsage};
+pub(crate) use response::ExtractFromResponse;
+use response::{ResponseBody, ResultCode};
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash, RandomState};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
+/// 한 번에 받는 최대 행 수
+pub(crate) const PAGE_SIZE: usize = 1000;
+
+type HttpsClient = Client<HttpsConnector<HttpConnector>, Empty<Bytes>>;
+
+// 같은 조회 조건의 진행 중인 요청
+type SharedBody = Shared<BoxFuture<'static, Result<Bytes, Arc<Error>>>>;
+
+/// 복제 비용이 작아 여러 작업에서 나눠 쓸 수 있으며, 복제본은 연결 풀을 함께 쓴다.
+#[derive(Clone)]
 pub struct NeisClient {
-    api_key: String,
-    client: Client<HttpsConnector<HttpConnector>, Empty<Bytes>>,
+    keys: Arc<KeyPool>,
+    timeout: Option<Duration>,
+    single_flight: bool,
+    shared: Arc<SharedState>,
 }
 
+struct SharedState {
+    client: HttpsClient,
+    in_flight: Mutex<HashMap<String, SharedBody>>,
+}
+
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<NeisClient>();
+};
+
 impl NeisClient {
     pub fn new(api_key: &str) -> Self {
+        Self::with_pool(KeyPool::new(&[api_key], KeyRotation::default()))
+    }
+
+    /// 여러 인증키를 돌아가며 쓰는 클라이언트
+    ///
+    /// 한도 초과(`ERROR-337`)나 등록되지 않은 키(`ERROR-290`) 응답을 받은 키는 멈추고
+    /// 다음 키로 다시 요청한다. 모든 키가 멈추면 마지막 응답의 [`Error::Api`] 를 돌려준다.
+    /// 한도 초과로 멈춘 키는 한국 표준시로 다음 날 다시 쓴다.
+    ///
+    /// 같은 키는 한 번만 쓰며, 키가 하나도 없으면 오류를 돌려준다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::{types::MealServiceParams, Error, KeyRotation, NeisClient};
+    ///
+    /// # async fn foo() -> Result<(), Error> {
+    /// let client = NeisClient::from_keys(&["KEY1", "KEY2", "KEY3"], KeyRotation::LeastUsed)?;
+    ///
+    /// let params = MealServiceParams::new("B10", "7010959").ymd(2025, 3, 4);
+    /// let items = client.meal_service(params).await?;
+    ///
+    /// for usage in client.key_usage() {
+    ///     println!("{}: {} {:?}", usage.key, usage.requests, usage.disabled);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_keys<S: AsRef<str>>(keys: &[S], rotation: KeyRotation) -> Result<Self, Error> {
+        if keys.is_empty() {
+            return Err(Error::new_unknown("no api key"));
+        }
+        Ok(Self::with_pool(KeyPool::new(keys, rotation)))
+    }
+
+    fn with_pool(keys: KeyPool) -> Self {
         let https = HttpsConnector::new();
         let client = Client::builder(TokioExecutor::new()).build(https);
 
         Self {
-            api_key: api_key.to_owned(),
-            client,
+            keys: Arc::new(keys),
+            timeout: None,
+            single_flight: false,
+            shared: Arc::new(SharedState {
+                client,
+                in_flight: Mutex::new(HashMap::new()),
+            }),
+        }
+    }
+
+    /// 인증키만 바꾼 복제본
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::NeisClient;
+    ///
+    /// let client = NeisClient::new("KEY1");
+    /// // 연결 풀은 함께 쓴다
+    /// let other = client.with_api_key("KEY2");
+    /// ```
+    pub fn with_api_key(&self, api_key: &str) -> Self {
+        Self {
+            keys: Arc::new(KeyPool::new(&[api_key], KeyRotation::default())),
+            ..self.clone()
+        }
+    }
+
+    /// 인증키별 요청 수와 사용 중지 여부
+    pub fn key_usage(&self) -> Vec<KeyUsage> {
+        self.keys.usage()
+    }
+
+    /// 학교기본정보를 한 건만 조회해 인증키를 확인한다.
+    ///
+    /// 여러 인증키를 쓰면 첫 번째 키를 확인한다. 다른 키는 [`NeisClient::with_api_key`] 로 확인한다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::{KeyStatus, NeisClient};
+    ///
+    /// # async fn foo(client: NeisClient) {
+    /// let pasted = "사용자가 입력한 키";
+    /// match client.with_api_key(pasted).validate_key().await {
+    ///     KeyStatus::Valid => {}
+    ///     KeyStatus::NetworkError(message) => eprintln!("다시 시도해 주세요: {}", message),
+    ///     status => eprintln!("사용할 수 없는 인증키: {:?}", status),
+    /// }
+    /// # }
+    /// ```
+    pub async fn validate_key(&self) -> KeyStatus {
+        let Some(key) = self.keys.first().filter(|key| !key.trim().is_empty()) else {
+            return KeyStatus::NoKey;
+        };
+        let url = format!(
+            "https://open.neis.go.kr/hub/{}?KEY={}&Type=json&pIndex=1&pSize=1",
+            SchoolInfoParams::RESOURCE,
+            encode(&key)
+        );
+
+        let body = match get_timeout(&self.shared.client, url, self.timeout).await {
+            Ok(body) => body,
+            Err(err) => return KeyStatus::NetworkError(err.to_string()),
+        };
+
+        match serde_json::from_slice(&body) {
+            // 해당하는 데이터 없음
+            Ok(ResponseBody::Result(code)) if !code.is_error() => KeyStatus::Valid,
+            Ok(ResponseBody::Result(code)) => match code.CODE.as_str() {
+                INVALID_KEY | MISSING_KEY => KeyStatus::Invalid,
+                SUSPENDED => KeyStatus::Suspended,
+                QUOTA_EXCEEDED => KeyStatus::QuotaExceeded,
+                _ => KeyStatus::Rejected {
+                    code: code.CODE.clone(),
+                    message: code.MESSAGE.clone(),
+                },
+            },
+            Ok(_) => KeyStatus::Valid,
+            Err(err) => KeyStatus::MalformedResponse(err.to_string()),
+        }
+    }
+
+    /// 멈춘 인증키를 모두 바로 다시 쓴다.
+    pub fn reset_keys(&self) {
+        self.keys.enable_all();
+    }
+
+    /// 페이지 요청 하나의 제한 시간을 바꾼 복제본, 넘기면 [`Error::Timeout`]
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::NeisClient;
+    /// use std::time::Duration;
+    ///
+    /// let client = NeisClient::new("KEY");
+    /// let quick = client.with_timeout(Duration::from_secs(3));
+    /// ```
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        Self {
+            timeout: Some(timeout),
+            ..self.clone()
+        }
+    }
+
+    /// 같은 API, 같은 조회 조건의 요청이 진행 중이면 새로 요청하지 않고 그 응답을 함께 받는 복제본
+    ///
+    /// 페이지 단위로 합치며, 실패하면 기다리던 요청 모두 같은 오류를 받는다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::{types::MealServiceParams, Error, NeisClient};
+    ///
+    /// # async fn foo() -> Result<(), Error> {
+    /// let api_key = std::env::var("NEIS_API_KEY").unwrap();
+    /// let client = NeisClient::new(&api_key).with_single_flight(true);
+    ///
+    /// let params = MealServiceParams::new("B10", "7010959").ymd(2025, 3, 4);
+    /// // HTTP 요청은 한 번
+    /// let (a, b) = futures_util::future::join(
+    ///     client.meal_service(params.clone()),
+    ///     client.meal_service(params),
+    /// )
+    /// .await;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_single_flight(&self, enabled: bool) -> Self {
+        Self {
+            single_flight: enabled,
+            ..self.clone()
         }
     }
 
@@ -51,7 +251,7 @@ impl NeisClient {
         &self,
         params: SchoolInfoParams,
     ) -> Result<Vec<SchoolInfoItem>, Error> {
-        self.request("schoolInfo", params).await
+        self.fetch(params).await
     }
 
     /// 학급정보
@@ -72,7 +272,7 @@ impl NeisClient {
     /// # }
     /// ```
     pub async fn class_info(&self, params: ClassInfoParams) -> Result<Vec<ClassInfoItem>, Error> {
-        self.request("classInfo", params).await
+        self.fetch(params).await
     }
 
     /// 학교학과정보
@@ -96,7 +296,7 @@ impl NeisClient {
         &self,
         params: SchoolMajorInfoParams,
     ) -> Result<Vec<SchoolMajorInfoItem>, Error> {
-        self.request("schoolMajorinfo", params).await
+        self.fetch(params).await
     }
 
     /// 학교계열정보
@@ -120,7 +320,7 @@ impl NeisClient {
         &self,
         params: SchoolAflcoInfoParams,
     ) -> Result<Vec<SchoolAflcoInfoItem>, Error> {
-        self.request("schulAflcoinfo", params).await
+        self.fetch(params).await
     }
 
     /// 학사일정
@@ -144,7 +344,7 @@ impl NeisClient {
         &self,
         params: SchoolScheduleParams,
     ) -> Result<Vec<SchoolScheduleItem>, Error> {
-        self.request("SchoolSchedule", params).await
+        self.fetch(params).await
     }
 
     /// 초등학교시간표
@@ -168,7 +368,7 @@ impl NeisClient {
         &self,
         params: ElsTimetableParams,
     ) -> Result<Vec<ElsTimetableItem>, Error> {
-        self.request("elsTimetable", params).await
+        self.fetch(params).await
     }
 
     /// 중학교시간표
@@ -192,7 +392,7 @@ impl NeisClient {
         &self,
         params: MisTimetableParams,
     ) -> Result<Vec<MisTimetableItem>, Error> {
-        self.request("misTimetable", params).await
+        self.fetch(params).await
     }
 
     /// 고등학교시간표
@@ -216,7 +416,7 @@ impl NeisClient {
         &self,
         params: HisTimetableParams,
     ) -> Result<Vec<HisTimetableItem>, Error> {
-        self.request("hisTimetable", params).await
+        self.fetch(params).await
     }
 
     /// 특수학교시간표
@@ -240,7 +440,7 @@ impl NeisClient {
         &self,
         params: SpsTimetableParams,
     ) -> Result<Vec<SpsTimetableItem>, Error> {
-        self.request("spsTimetable", params).await
+        self.fetch(params).await
     }
 
     /// 시간표강의실정보
@@ -264,7 +464,7 @@ impl NeisClient {
         &self,
         params: ClassRoomInfoParams,
     ) -> Result<Vec<ClassRoomInfoItem>, Error> {
-        self.request("tiClrminfo", params).await
+        self.fetch(params).await
     }
 
     /// 학원교습소정보
@@ -288,7 +488,7 @@ impl NeisClient {
         &self,
         params: AcademyInfoParams,
     ) -> Result<Vec<AcademyInfoItem>, Error> {
-        self.request("acaInsTiInfo", params).await
+        self.fetch(params).await
     }
 
     /// 급식식단정보
@@ -312,7 +512,201 @@ impl NeisClient {
         &self,
         params: MealServiceParams,
     ) -> Result<Vec<MealServiceItem>, Error> {
-        self.request("mealServiceDietInfo", params).await
+        self.fetch(params).await
+    }
+
+    /// 학교종류에 맞는 시간표
+    ///
+    /// 학교기본정보로 학교종류를 조회한 뒤 초등학교, 중학교, 고등학교, 특수학교 시간표 중
+    /// 알맞은 API 를 호출한다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::{timetable::TimetableQuery, Error, NeisClient};
+    ///
+    /// # async fn foo() -> Result<(), Error> {
+    /// let api_key = std::env::var("NEIS_API_KEY").unwrap();
+    /// let client = NeisClient::new(&api_key);
+    ///
+    /// let query = TimetableQuery::new().grade(3).class_nm("1").ymd(2023, 8, 21);
+    /// let items = client.timetable("B10", "7010959", query).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn timetable(
+        &self,
+        atpt_ofcdc_sc_code: &str,
+        sd_schul_code: &str,
+        query: TimetableQuery,
+    ) -> Result<Vec<TimetableItem>, Error> {
+        let params = SchoolInfoParams::school_code(sd_schul_code).office(atpt_ofcdc_sc_code);
+        let school = self
+            .school_info(params)
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| {
+                Error::new_unknown(&format!(
+                    "school not found: {} {}",
+                    atpt_ofcdc_sc_code, sd_schul_code
+                ))
+            })?;
+
+        self.timetable_for(&school, query).await
+    }
+
+    /// 이미 조회한 학교기본정보로 학교종류에 맞는 시간표를 조회
+    pub async fn timetable_for(
+        &self,
+        school: &SchoolInfoItem,
+        query: TimetableQuery,
+    ) -> Result<Vec<TimetableItem>, Error> {
+        let office = school.ATPT_OFCDC_SC_CODE.as_str();
+        let code = school.SD_SCHUL_CODE.as_str();
+        let school_kind = school.school_kind();
+        let kind = school_kind
+            .as_ref()
+            .and_then(TimetableKind::for_school_kind)
+            .ok_or_else(|| {
+                Error::UnsupportedSchoolKind(school_kind.map(|k| k.to_string()).unwrap_or_default())
+            })?;
+
+        let items = match kind {
+            TimetableKind::Els => {
+                into_items(self.els_timetable(query.els_params(office, code)).await?)
+            }
+            TimetableKind::Mis => {
+                into_items(self.mis_timetable(query.mis_params(office, code)).await?)
+            }
+            TimetableKind::His => {
+                into_items(self.his_timetable(query.his_params(office, code)).await?)
+            }
+            TimetableKind::Sps => {
+                into_items(self.sps_timetable(query.sps_params(office, code)).await?)
+            }
+        };
+
+        Ok(items)
+    }
+
+    /// 여러 조회 조건을 최대 `concurrency` 개씩 동시에 조회
+    ///
+    /// 결과는 입력 순서대로이며, 하나가 실패해도 나머지는 계속 조회한다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::{types::MealServiceParams, Error, NeisClient};
+    ///
+    /// # async fn foo() -> Result<(), Error> {
+    /// let api_key = std::env::var("NEIS_API_KEY").unwrap();
+    /// let client = NeisClient::new(&api_key);
+    ///
+    /// let schools = ["7010959", "7010536", "7031115"];
+    /// let params = schools
+    ///     .iter()
+    ///     .map(|code| MealServiceParams::new("B10", code).ymd(2025, 3, 4));
+    ///
+    /// for (code, result) in schools.iter().zip(client.batch(params, 8).await) {
+    ///     match result {
+    ///         Ok(items) => println!("{}: {}", code, items.len()),
+    ///         Err(err) => eprintln!("{}: {}", code, err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn batch<I, P>(
+        &self,
+        params: I,
+        concurrency: usize,
+    ) -> Vec<Result<Vec<P::Item>, Error>>
+    where
+        I: IntoIterator<Item = P>,
+        P: Endpoint,
+        P::Item: ExtractFromResponse,
+    {
+        stream::iter(params)
+            .map(|params| self.fetch(params))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// 조회 기간을 `chunk` 단위로 나눠 최대 `concurrency` 개씩 동시에 조회
+    ///
+    /// 결과는 기간 순서대로 합치고 같은 행은 하나만 남긴다.
+    /// 시작일자나 종료일자 중 하나라도 없으면 나누지 않고 조회하며,
+    /// 시작일자가 종료일자보다 늦으면 조회하지 않고 `Err` 를 돌려준다.
+    /// 한 기간이라도 실패하면 남은 기간은 조회하지 않는다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::{types::{Chunk, HisTimetableParams}, Error, NeisClient};
+    ///
+    /// # async fn foo() -> Result<(), Error> {
+    /// let api_key = std::env::var("NEIS_API_KEY").unwrap();
+    /// let client = NeisClient::new(&api_key);
+    ///
+    /// let params = HisTimetableParams::new("B10", "7010959")
+    ///     .from_ymd(2024, 3, 1)
+    ///     .to_ymd(2025, 2, 28);
+    /// let items = client.fetch_chunked(params, Chunk::Month, 4).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_chunked<P>(
+        &self,
+        params: P,
+        chunk: Chunk,
+        concurrency: usize,
+    ) -> Result<Vec<P::Item>, Error>
+    where
+        P: Endpoint + DateRange + Clone,
+        P::Item: ExtractFromResponse + Hash,
+    {
+        let Some((from, to)) = params.date_range() else {
+            return self.fetch(params).await;
+        };
+        if from > to {
+            return Err(Error::new_unknown(&format!(
+                "invalid date range: {} is after {}",
+                from.format("%Y%m%d"),
+                to.format("%Y%m%d")
+            )));
+        }
+
+        let chunks = chunk
+            .split(from, to)
+            .into_iter()
+            .map(|(from, to)| params.clone().with_date_range(from, to));
+        let mut results = stream::iter(chunks)
+            .map(|params| self.fetch(params))
+            .buffered(concurrency.max(1));
+
+        // 행을 복사해 두지 않고 해시만 비교한다
+        let state = RandomState::new();
+        let mut seen = HashSet::new();
+        let mut items = Vec::new();
+        while let Some(result) = results.next().await {
+            items.extend(
+                result?
+                    .into_iter()
+                    .filter(|item| seen.insert(state.hash_one(item))),
+            );
+        }
+        Ok(items)
+    }
+
+    /// 조회 조건에 해당하는 API 의 모든 페이지
+    pub async fn fetch<P>(&self, params: P) -> Result<Vec<P::Item>, Error>
+    where
+        P: Endpoint,
+        P::Item: ExtractFromResponse,
+    {
+        self.request(P::RESOURCE, params).await
     }
 
     pub async fn request<P, T>(&self, resouce: &str, params: P) -> Result<Vec<T>, Error>
@@ -321,44 +715,216 @@ impl NeisClient {
         T: ExtractFromResponse,
     {
         let mut page = 1;
-        let page_size = 1000;
         let mut items = Vec::new();
 
         loop {
-            let common_params = format!(
-                "KEY={}&Type=json&pIndex={}&pSize={}",
-                self.api_key, page, page_size
-            );
-            let url = format!(
-                "https://open.neis.go.kr/hub/{}?{}&{}",
-                resouce,
-                common_params,
-                params.to_query_string()
-            );
+            let (total, row) = self.request_page(resouce, &params, page).await?;
+            items.extend(row);
 
-            let res = self.client.get(url.try_into().unwrap()).await?;
-            let status = res.status();
-            let body = res.collect().await?.to_bytes();
-            tracing::trace!(?body);
+            // 데이터가 없는 경우에도 total 이 0 이므로 loop 를 빠져나감
+            if total > page * PAGE_SIZE {
+                page += 1;
+            } else {
+                break; // while loop
+            }
+        }
 
-            if status.is_success() {
-                let data: ResponseBody = serde_json::from_slice(&body)?;
+        Ok(items)
+    }
 
-                let (total, row) = T::extract_from_response(data);
+    /// 조회 조건의 한 페이지
+    #[cfg(feature = "crawler")]
+    pub(crate) async fn fetch_page<P>(
+        &self,
+        params: &P,
+        page: usize,
+    ) -> Result<(usize, Vec<P::Item>), Error>
+    where
+        P: Endpoint,
+        P::Item: ExtractFromResponse,
+    {
+        self.request_page(P::RESOURCE, params, page).await
+    }
+
+    /// 한 페이지와 전체 건수
+    async fn request_page<P, T>(
+        &self,
+        resouce: &str,
+        params: &P,
+        page: usize,
+    ) -> Result<(usize, Vec<T>), Error>
+    where
+        P: ToQueryString,
+        T: ExtractFromResponse,
+    {
+        let query = format!(
+            "Type=json&pIndex={}&pSize={}&{}",
+            page,
+            PAGE_SIZE,
+            params.to_query_string()
+        );
 
-                items.extend(row);
+        let body = if self.single_flight {
+            self.send_shared(resouce, query).await?
+        } else {
+            send(
+                self.shared.client.clone(),
+                self.keys.clone(),
+                self.timeout,
+                resouce.to_owned(),
+                query,
+            )
+            .await?
+        };
 
-                // 데이터가 없는 경우에도 total 이 0 이므로 loop 를 빠져나감
-                if total > page * page_size {
-                    page += 1;
-                } else {
-                    break; // while loop
-                }
-            } else {
-                return Err(Error::new_unknown(&format!("status: {}", status)));
+        let data: ResponseBody = serde_json::from_slice(&body)?;
+        if let ResponseBody::Result(code) = &data
+            && code.is_error()
+        {
+            return Err(Error::Api {
+                code: code.CODE.clone(),
+                message: code.MESSAGE.clone(),
+            });
+        }
+        Ok(T::extract_from_response(data))
+    }
+
+    /// 같은 인증키 묶음으로 같은 API, 같은 조회 조건을 받는 중이면 그 응답을 함께 기다림
+    async fn send_shared(&self, resouce: &str, query: String) -> Result<Bytes, Error> {
+        // 묶음 안에서는 키를 돌아가며 쓰므로 키 대신 묶음으로 구분하고,
+        // 제한 시간이 다른 복제본끼리는 합치지 않는다
+        let id = format!(
+            "{:p}/{:?}/{}?{}",
+            Arc::as_ptr(&self.keys),
+            self.timeout,
+            resouce,
+            query
+        );
+        let pending = self
+            .shared
+            .in_flight
+            .lock()
+            .unwrap()
+            .entry(id.clone())
+            .or_insert_with(|| {
+                send(
+                    self.shared.client.clone(),
+                    self.keys.clone(),
+                    self.timeout,
+                    resouce.to_owned(),
+                    query,
+                )
+                .map(|result| result.map_err(Arc::new))
+                .boxed()
+                .shared()
+            })
+            .clone();
+
+        let mut waiter = Waiter {
+            in_flight: &self.shared.in_flight,
+            id,
+            pending: Some(pending),
+        };
+        let pending = waiter.pending.as_mut().expect("pending until dropped");
+        pending.await.map_err(Error::from_shared)
+    }
+}
+
+/// 함께 기다리는 요청 하나, 끝나거나 취소되면 표에서 정리한다.
+struct Waiter<'a> {
+    in_flight: &'a Mutex<HashMap<String, SharedBody>>,
+    id: String,
+    // 표의 항목 복제는 모두 잠근 채로 만들고 버린다
+    pending: Option<SharedBody>,
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let Some(pending) = self.pending.take() else {
+            return;
+        };
+        // 그 사이 새로 시작된 요청은 남김
+        let ours = in_flight.get(&self.id).is_some_and(|s| s.ptr_eq(&pending));
+        drop(pending);
+
+        // 끝났거나, 표에 있는 것 말고 기다리는 쪽이 없을 때만 제거
+        if ours
+            && in_flight
+                .get(&self.id)
+                .is_some_and(|s| s.strong_count().is_none_or(|count| count <= 1))
+        {
+            in_flight.remove(&self.id);
+        }
+    }
+}
+
+/// 인증키를 골라 요청하고, 키 문제로 거절되면 다음 키로 다시 요청
+async fn send(
+    client: HttpsClient,
+    keys: Arc<KeyPool>,
+    timeout: Option<Duration>,
+    resouce: String,
+    query: String,
+) -> Result<Bytes, Error> {
+    loop {
+        let key = keys.next()?;
+        let url = format!(
+            "https://open.neis.go.kr/hub/{}?KEY={}&{}",
+            resouce,
+            encode(&key),
+            query
+        );
+
+        let body = get_timeout(&client, url, timeout).await?;
+
+        match ResultCode::from_body(&body) {
+            Some(code) if code.CODE == QUOTA_EXCEEDED || code.CODE == INVALID_KEY => {
+                tracing::warn!(code = %code.CODE, "api key disabled");
+                keys.disable(&key, &code.CODE, &code.MESSAGE);
             }
+            _ => return Ok(body),
         }
+    }
+}
 
-        Ok(items)
+async fn get_timeout(
+    client: &HttpsClient,
+    url: String,
+    timeout: Option<Duration>,
+) -> Result<Bytes, Error> {
+    match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, get(client, url))
+            .await
+            .map_err(|_| Error::Timeout(timeout))?,
+        None => get(client, url).await,
+    }
+}
+
+async fn get(client: &HttpsClient, url: String) -> Result<Bytes, Error> {
+    let uri: hyper::Uri = url
+        .parse()
+        .map_err(|err| Error::new_unknown(&format!("invalid url: {}", err)))?;
+    let res = client.get(uri).await?;
+    let status = res.status();
+    let body = res.collect().await?.to_bytes();
+    tracing::trace!(?body);
+
+    if status.is_success() {
+        Ok(body)
+    } else {
+        Err(Error::new_unknown(&format!("status: {}", status)))
     }
 }
+
+/// 쿼리 문자열에 넣을 수 있게 인증키를 인코딩
+fn encode(key: &str) -> String {
+    form_urlencoded::byte_serialize(key.as_bytes()).collect()
+}
+
+pub(crate) fn into_items<T: Into<TimetableItem>>(items: Vec<T>) -> Vec<TimetableItem> {
+    items.into_iter().map(Into::into).collect()
+}
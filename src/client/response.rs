@@ -12,8 +12,23 @@ use super::AcademyInfoItem;
 
 #[derive(Debug, Deserialize)]
 pub struct ResultCode {
-    CODE: String,
-    MESSAGE: String,
+    pub CODE: String,
+    pub MESSAGE: String,
+}
+
+impl ResultCode {
+    /// 정상(`INFO-000`)이나 해당 데이터 없음(`INFO-200`)이 아닌 결과
+    pub fn is_error(&self) -> bool {
+        !matches!(self.CODE.as_str(), "INFO-000" | "INFO-200")
+    }
+
+    /// 항목 없이 결과 코드만 온 응답
+    pub fn from_body(body: &[u8]) -> Option<Self> {
+        match serde_json::from_slice(body) {
+            Ok(ResponseBody::Result(code)) => Some(code),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
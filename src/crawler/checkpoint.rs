@@ -0,0 +1,121 @@
+use super::CrawlEndpoint;
+use crate::Error;
+use std::collections::{BTreeSet, HashMap};
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+/// 끝난 (학교, API, 페이지) 단위
+///
+/// 파일 첫 줄은 `#\t조회조건` 이고, 그 뒤로 한 줄에 하나씩
+/// `행정표준코드\tAPI\t페이지\t전체건수` 로 덧붙인다.
+/// 페이지가 `0` 인 줄은 그 (학교, API) 의 기록을 지운다.
+/// 중단되어 잘린 마지막 줄은 읽을 때 무시한다.
+#[derive(Debug, Default)]
+pub(super) struct Checkpoint {
+    file: Option<File>,
+    done: HashMap<(String, CrawlEndpoint), Progress>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub(super) struct Progress {
+    /// 가장 최근에 받은 전체 건수
+    pub total: Option<usize>,
+    pub pages: BTreeSet<usize>,
+}
+
+impl Checkpoint {
+    /// 다른 조회조건으로 기록한 파일이면 `Err`
+    pub fn open(path: &Path, query: &str) -> Result<Self, Error> {
+        let header = format!("#\t{}", query);
+        let mut checkpoint = Self::default();
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut lines = text.lines();
+        match lines.next() {
+            Some(line) if line == header => {}
+            None => {}
+            Some(line) => {
+                return Err(Error::new_unknown(&format!(
+                    "checkpoint {} was written for another query: {:?}, expected {:?}",
+                    path.display(),
+                    line.strip_prefix("#\t").unwrap_or_default(),
+                    query
+                )));
+            }
+        }
+        for line in lines {
+            if let Some((school, endpoint, page, total)) = parse_line(line) {
+                checkpoint.insert(school, endpoint, page, total);
+            }
+        }
+
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        if text.is_empty() {
+            writeln!(file, "{}", header)?;
+            file.flush()?;
+        }
+        checkpoint.file = Some(file);
+        Ok(checkpoint)
+    }
+
+    pub fn progress(&self, school: &str, endpoint: CrawlEndpoint) -> Progress {
+        self.done
+            .get(&(school.to_owned(), endpoint))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn record(
+        &mut self,
+        school: &str,
+        endpoint: CrawlEndpoint,
+        page: usize,
+        total: usize,
+    ) -> Result<(), Error> {
+        if let Some(file) = &mut self.file {
+            writeln!(
+                file,
+                "{}\t{}\t{}\t{}",
+                school,
+                endpoint.as_str(),
+                page,
+                total
+            )?;
+            file.flush()?;
+        }
+        self.insert(school, endpoint, page, total);
+        Ok(())
+    }
+
+    /// 전체 건수가 바뀐 (학교, API) 의 기록을 지운다.
+    pub fn reset(&mut self, school: &str, endpoint: CrawlEndpoint) -> Result<(), Error> {
+        self.record(school, endpoint, 0, 0)
+    }
+
+    fn insert(&mut self, school: &str, endpoint: CrawlEndpoint, page: usize, total: usize) {
+        if page == 0 {
+            self.done.remove(&(school.to_owned(), endpoint));
+            return;
+        }
+        let progress = self.done.entry((school.to_owned(), endpoint)).or_default();
+        progress.total = Some(total);
+        progress.pages.insert(page);
+    }
+}
+
+fn parse_line(line: &str) -> Option<(&str, CrawlEndpoint, usize, usize)> {
+    let mut fields = line.split('\t');
+    let school = fields.next()?;
+    let endpoint = CrawlEndpoint::from_name(fields.next()?)?;
+    let page = fields.next()?.parse().ok()?;
+    let total = fields.next()?.parse().ok()?;
+    if fields.next().is_some() {
+        return None;
+    }
+    Some((school, endpoint, page, total))
+}
@@ -0,0 +1,593 @@
+//! 교육청 단위 대량 수집
+//!
+//! 교육청의 모든 학교를 학교기본정보로 조회한 뒤, 모든 교육청의 학교마다 지정한 API 를 페이지 단위로 받는다.
+//! 받은 페이지는 콜백에 넘긴 다음 체크포인트 파일에 기록하므로,
+//! 중단 후 다시 실행하면 기록되지 않은 페이지부터 이어서 받는다.
+
+mod checkpoint;
+
+use crate::client::{PAGE_SIZE, into_items};
+use crate::timetable::{TimetableItem, TimetableKind, TimetableQuery};
+use crate::types::*;
+use crate::{Error, NeisClient};
+use checkpoint::{Checkpoint, Progress};
+use chrono::{Datelike, NaiveDate};
+use futures_util::StreamExt;
+use futures_util::stream::{self, Stream};
+use std::path::PathBuf;
+
+/// 학교마다 수집할 API
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CrawlEndpoint {
+    /// 학급정보
+    ClassInfo,
+    /// 학교학과정보
+    SchoolMajorInfo,
+    /// 학교계열정보
+    SchoolAflcoInfo,
+    /// 학사일정
+    SchoolSchedule,
+    /// 학교종류에 맞는 시간표
+    Timetable,
+    /// 시간표강의실정보
+    ClassRoomInfo,
+    /// 급식식단정보
+    MealService,
+}
+
+impl CrawlEndpoint {
+    /// 체크포인트에 기록하는 이름
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CrawlEndpoint::ClassInfo => ClassInfoParams::RESOURCE,
+            CrawlEndpoint::SchoolMajorInfo => SchoolMajorInfoParams::RESOURCE,
+            CrawlEndpoint::SchoolAflcoInfo => SchoolAflcoInfoParams::RESOURCE,
+            CrawlEndpoint::SchoolSchedule => SchoolScheduleParams::RESOURCE,
+            CrawlEndpoint::Timetable => "timetable",
+            CrawlEndpoint::ClassRoomInfo => ClassRoomInfoParams::RESOURCE,
+            CrawlEndpoint::MealService => MealServiceParams::RESOURCE,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            CrawlEndpoint::ClassInfo,
+            CrawlEndpoint::SchoolMajorInfo,
+            CrawlEndpoint::SchoolAflcoInfo,
+            CrawlEndpoint::SchoolSchedule,
+            CrawlEndpoint::Timetable,
+            CrawlEndpoint::ClassRoomInfo,
+            CrawlEndpoint::MealService,
+        ]
+        .into_iter()
+        .find(|endpoint| endpoint.as_str() == name)
+    }
+}
+
+impl std::fmt::Display for CrawlEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 한 페이지의 항목
+#[derive(Debug, Clone)]
+pub enum CrawlData {
+    ClassInfo(Vec<ClassInfoItem>),
+    SchoolMajorInfo(Vec<SchoolMajorInfoItem>),
+    SchoolAflcoInfo(Vec<SchoolAflcoInfoItem>),
+    SchoolSchedule(Vec<SchoolScheduleItem>),
+    Timetable(Vec<TimetableItem>),
+    ClassRoomInfo(Vec<ClassRoomInfoItem>),
+    MealService(Vec<MealServiceItem>),
+}
+
+impl CrawlData {
+    pub fn len(&self) -> usize {
+        match self {
+            CrawlData::ClassInfo(items) => items.len(),
+            CrawlData::SchoolMajorInfo(items) => items.len(),
+            CrawlData::SchoolAflcoInfo(items) => items.len(),
+            CrawlData::SchoolSchedule(items) => items.len(),
+            CrawlData::Timetable(items) => items.len(),
+            CrawlData::ClassRoomInfo(items) => items.len(),
+            CrawlData::MealService(items) => items.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 실패한 수집 단위
+#[derive(Debug, Clone)]
+pub struct CrawlFailure {
+    /// 행정표준코드, 학교 목록 조회에 실패했으면 `None`
+    pub school: Option<String>,
+    pub endpoint: Option<CrawlEndpoint>,
+    pub message: String,
+}
+
+/// 교육청별 수집 결과
+#[derive(Debug, Clone, Default)]
+pub struct OfficeReport {
+    /// 시도교육청코드
+    pub office: String,
+    pub schools: usize,
+    /// 이번 실행에서 받은 페이지
+    pub pages: usize,
+    /// 체크포인트에 있어 건너뛴 페이지
+    pub resumed_pages: usize,
+    /// 이번 실행에서 받은 행
+    pub rows: usize,
+    /// 시간표 API 가 없는 학교
+    pub unsupported: usize,
+    pub failures: Vec<CrawlFailure>,
+}
+
+impl std::fmt::Display for OfficeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}: 학교 {}, 페이지 {} (이어받기 {}), 행 {}, 시간표 없음 {}, 실패 {}",
+            self.office,
+            self.schools,
+            self.pages,
+            self.resumed_pages,
+            self.rows,
+            self.unsupported,
+            self.failures.len()
+        )
+    }
+}
+
+/// 교육청 단위 수집기
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use neis_client::crawler::{CrawlData, CrawlEndpoint, Crawler};
+/// use neis_client::{Error, NeisClient};
+///
+/// # async fn foo() -> Result<(), Error> {
+/// let api_key = std::env::var("NEIS_API_KEY").unwrap();
+/// let client = NeisClient::new(&api_key);
+///
+/// let mut meals = 0;
+/// let reports = Crawler::new(&client)
+///     .offices(&["B10", "C10"])
+///     .endpoints(&[CrawlEndpoint::Timetable, CrawlEndpoint::MealService])
+///     .date_range(
+///         NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+///         NaiveDate::from_ymd_opt(2025, 3, 31).unwrap(),
+///     )
+///     .concurrency(8)
+///     .checkpoint("crawl.checkpoint")
+///     .run(|school, data| {
+///         if let CrawlData::MealService(items) = data {
+///             println!("{}: {}", school.SCHUL_NM, items.len());
+///             meals += items.len();
+///         }
+///     })
+///     .await?;
+///
+/// for report in reports {
+///     println!("{}", report);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Crawler<'a> {
+    client: &'a NeisClient,
+    offices: Vec<String>,
+    endpoints: Vec<CrawlEndpoint>,
+    concurrency: usize,
+    checkpoint: Option<PathBuf>,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    ay: Option<i32>,
+}
+
+impl<'a> Crawler<'a> {
+    pub fn new(client: &'a NeisClient) -> Self {
+        Self {
+            client,
+            offices: Vec::new(),
+            endpoints: Vec::new(),
+            concurrency: 4,
+            checkpoint: None,
+            from: None,
+            to: None,
+            ay: None,
+        }
+    }
+
+    /// 시도교육청코드
+    pub fn offices(mut self, offices: &[&str]) -> Self {
+        self.offices = offices.iter().map(|s| s.to_string()).collect();
+        self
+    }
+
+    pub fn endpoints(mut self, endpoints: &[CrawlEndpoint]) -> Self {
+        self.endpoints = endpoints.to_vec();
+        self
+    }
+
+    /// 동시에 받는 (학교, API) 수, 학교 목록도 이만큼 동시에 받는다.
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// 끝난 페이지를 기록하고 이어받을 파일
+    ///
+    /// 조회 기간과 학년도를 함께 기록하며, 다른 조건으로 기록한 파일이면 [`Crawler::run`] 이 실패한다.
+    pub fn checkpoint<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.checkpoint = Some(path.into());
+        self
+    }
+
+    /// 학사일정, 시간표, 급식식단의 조회 기간
+    pub fn date_range(mut self, from: NaiveDate, to: NaiveDate) -> Self {
+        self.from = Some(from);
+        self.to = Some(to);
+        self
+    }
+
+    /// 학급정보, 시간표, 시간표강의실정보의 학년도
+    pub fn ay(mut self, year: i32) -> Self {
+        self.ay = Some(year);
+        self
+    }
+
+    /// 모든 교육청의 (학교, API) 를 함께 수집하고 교육청별 결과를 돌려준다.
+    ///
+    /// 실패한 (학교, API) 는 결과에 기록하고 계속 진행하며, 다시 실행하면 다시 시도한다.
+    /// 한도 초과 같은 오류 결과 코드를 받은 페이지도 실패로 보고 체크포인트에 기록하지 않는다.
+    ///
+    /// 이어받을 때 처음 받은 페이지의 전체 건수가 체크포인트와 다르면 그 사이 자료가 바뀐 것으로 보고
+    /// 그 (학교, API) 를 첫 페이지부터 다시 받는다. 모든 페이지를 이미 받은 (학교, API) 는 다시 확인하지 않는다.
+    ///
+    /// 체크포인트 파일을 열거나 쓸 수 없거나 다른 조회조건으로 기록된 경우에만 `Err`
+    pub async fn run<F>(&self, mut on_page: F) -> Result<Vec<OfficeReport>, Error>
+    where
+        F: FnMut(&SchoolInfoItem, CrawlData),
+    {
+        let mut checkpoint = match &self.checkpoint {
+            Some(path) => Checkpoint::open(path, &self.query())?,
+            None => Checkpoint::default(),
+        };
+
+        let schools: Vec<_> = stream::iter(&self.offices)
+            .map(|office| {
+                self.client
+                    .school_info(SchoolInfoParams::default().office(office))
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut reports = Vec::new();
+        let mut units = Vec::new();
+        for (index, (office, schools)) in self.offices.iter().zip(&schools).enumerate() {
+            let mut report = OfficeReport {
+                office: office.clone(),
+                ..Default::default()
+            };
+            match schools {
+                Ok(schools) => {
+                    report.schools = schools.len();
+                    for school in schools {
+                        for endpoint in &self.endpoints {
+                            units.push(Unit {
+                                office: index,
+                                school,
+                                endpoint: *endpoint,
+                                progress: checkpoint.progress(&school.SD_SCHUL_CODE, *endpoint),
+                            });
+                        }
+                    }
+                }
+                Err(err) => report.failures.push(CrawlFailure {
+                    school: None,
+                    endpoint: None,
+                    message: err.to_string(),
+                }),
+            }
+            reports.push(report);
+        }
+
+        let mut events = stream::iter(units)
+            .map(|unit| Box::pin(self.crawl(unit)))
+            .flatten_unordered(self.concurrency);
+        while let Some(event) = events.next().await {
+            match event {
+                Event::Page {
+                    school,
+                    endpoint,
+                    page,
+                    total,
+                    data,
+                } => {
+                    on_page(school, data);
+                    checkpoint.record(&school.SD_SCHUL_CODE, endpoint, page, total)?;
+                }
+                Event::Restart { school, endpoint } => {
+                    checkpoint.reset(&school.SD_SCHUL_CODE, endpoint)?;
+                }
+                Event::Done { office, outcome } => {
+                    let report = &mut reports[office];
+                    report.pages += outcome.pages;
+                    report.resumed_pages += outcome.resumed_pages;
+                    report.rows += outcome.rows;
+                    if outcome.unsupported {
+                        report.unsupported += 1;
+                    }
+                    report.failures.extend(outcome.failure);
+                }
+            }
+        }
+
+        Ok(reports)
+    }
+
+    /// 한 학교의 한 API 를 끝까지, 페이지마다 [`Event::Page`] 를 내고 [`Event::Done`] 으로 끝난다.
+    fn crawl<'s>(&'s self, unit: Unit<'s>) -> impl Stream<Item = Event<'s>> + 's {
+        let state = UnitState {
+            page: 1,
+            total: unit.progress.total,
+            restarted: false,
+            outcome: Outcome::default(),
+            unit,
+        };
+
+        stream::unfold(Some(state), move |state| async move {
+            let mut state = state?;
+            let school = state.unit.school;
+            let endpoint = state.unit.endpoint;
+            let fail = |message: String| CrawlFailure {
+                school: Some(school.SD_SCHUL_CODE.clone()),
+                endpoint: Some(endpoint),
+                message,
+            };
+
+            loop {
+                if state
+                    .total
+                    .is_some_and(|total| state.page > total.div_ceil(PAGE_SIZE).max(1))
+                {
+                    return Some((state.done(), None));
+                }
+                if state.total.is_some() && state.unit.progress.pages.contains(&state.page) {
+                    state.outcome.resumed_pages += 1;
+                    state.page += 1;
+                    continue;
+                }
+
+                match self.fetch(school, endpoint, state.page).await {
+                    Ok(Some((count, _))) if state.total.is_some_and(|total| total != count) => {
+                        if state.restarted {
+                            state.outcome.failure = Some(fail(format!(
+                                "total changed from {} to {} while crawling",
+                                state.total.unwrap_or_default(),
+                                count
+                            )));
+                            return Some((state.done(), None));
+                        }
+                        // 받은 페이지는 버리고 첫 페이지부터 다시
+                        state.restarted = true;
+                        state.unit.progress = Progress::default();
+                        state.total = None;
+                        state.page = 1;
+                        state.outcome.resumed_pages = 0;
+                        return Some((Event::Restart { school, endpoint }, Some(state)));
+                    }
+                    Ok(Some((count, data))) => {
+                        let page = state.page;
+                        state.total = Some(count);
+                        state.page += 1;
+                        state.outcome.pages += 1;
+                        state.outcome.rows += data.len();
+                        let event = Event::Page {
+                            school,
+                            endpoint,
+                            page,
+                            total: count,
+                            data,
+                        };
+                        return Some((event, Some(state)));
+                    }
+                    Ok(None) => {
+                        state.outcome.unsupported = true;
+                        return Some((state.done(), None));
+                    }
+                    Err(err) => {
+                        state.outcome.failure = Some(fail(err.to_string()));
+                        return Some((state.done(), None));
+                    }
+                }
+            }
+        })
+    }
+
+    /// 체크포인트에 기록하는 조회조건
+    fn query(&self) -> String {
+        let ymd = |date: Option<NaiveDate>| {
+            date.map(|d| d.format("%Y%m%d").to_string())
+                .unwrap_or_default()
+        };
+        format!(
+            "from={}&to={}&ay={}",
+            ymd(self.from),
+            ymd(self.to),
+            self.ay.map(|ay| ay.to_string()).unwrap_or_default()
+        )
+    }
+
+    /// 시간표 API 가 없는 학교면 `None`
+    async fn fetch(
+        &self,
+        school: &SchoolInfoItem,
+        endpoint: CrawlEndpoint,
+        page: usize,
+    ) -> Result<Option<(usize, CrawlData)>, Error> {
+        let office = school.ATPT_OFCDC_SC_CODE.as_str();
+        let code = school.SD_SCHUL_CODE.as_str();
+        let client = self.client;
+
+        let page = match endpoint {
+            CrawlEndpoint::ClassInfo => {
+                let mut params = ClassInfoParams::new(office, code);
+                if let Some(ay) = self.ay {
+                    params = params.ay(ay);
+                }
+                let (total, items) = client.fetch_page(&params, page).await?;
+                (total, CrawlData::ClassInfo(items))
+            }
+            CrawlEndpoint::SchoolMajorInfo => {
+                let params = SchoolMajorInfoParams::new(office).school_code(code);
+                let (total, items) = client.fetch_page(&params, page).await?;
+                (total, CrawlData::SchoolMajorInfo(items))
+            }
+            CrawlEndpoint::SchoolAflcoInfo => {
+                let params = SchoolAflcoInfoParams::new(office).school_code(code);
+                let (total, items) = client.fetch_page(&params, page).await?;
+                (total, CrawlData::SchoolAflcoInfo(items))
+            }
+            CrawlEndpoint::SchoolSchedule => {
+                let mut params = SchoolScheduleParams::new(office, code);
+                if let Some(from) = self.from {
+                    params = params.from_ymd(from.year(), from.month() as u8, from.day() as u8);
+                }
+                if let Some(to) = self.to {
+                    params = params.to_ymd(to.year(), to.month() as u8, to.day() as u8);
+                }
+                let (total, items) = client.fetch_page(&params, page).await?;
+                (total, CrawlData::SchoolSchedule(items))
+            }
+            CrawlEndpoint::Timetable => {
+                let Some(kind) = school
+                    .school_kind()
+                    .as_ref()
+                    .and_then(TimetableKind::for_school_kind)
+                else {
+                    return Ok(None);
+                };
+
+                let mut query = TimetableQuery::new();
+                if let Some(ay) = self.ay {
+                    query = query.ay(ay);
+                }
+                if let Some(from) = self.from {
+                    query = query.from_ymd(from.year(), from.month() as u8, from.day() as u8);
+                }
+                if let Some(to) = self.to {
+                    query = query.to_ymd(to.year(), to.month() as u8, to.day() as u8);
+                }
+
+                let (total, items) = match kind {
+                    TimetableKind::Els => {
+                        let params = query.els_params(office, code);
+                        let (total, items) = client.fetch_page(&params, page).await?;
+                        (total, into_items(items))
+                    }
+                    TimetableKind::Mis => {
+                        let params = query.mis_params(office, code);
+                        let (total, items) = client.fetch_page(&params, page).await?;
+                        (total, into_items(items))
+                    }
+                    TimetableKind::His => {
+                        let params = query.his_params(office, code);
+                        let (total, items) = client.fetch_page(&params, page).await?;
+                        (total, into_items(items))
+                    }
+                    TimetableKind::Sps => {
+                        let params = query.sps_params(office, code);
+                        let (total, items) = client.fetch_page(&params, page).await?;
+                        (total, into_items(items))
+                    }
+                };
+                (total, CrawlData::Timetable(items))
+            }
+            CrawlEndpoint::ClassRoomInfo => {
+                let mut params = ClassRoomInfoParams::new(office, code);
+                if let Some(ay) = self.ay {
+                    params = params.ay(ay);
+                }
+                let (total, items) = client.fetch_page(&params, page).await?;
+                (total, CrawlData::ClassRoomInfo(items))
+            }
+            CrawlEndpoint::MealService => {
+                let mut params = MealServiceParams::new(office, code);
+                if let Some(from) = self.from {
+                    params = params.from_ymd(from.year(), from.month() as u8, from.day() as u8);
+                }
+                if let Some(to) = self.to {
+                    params = params.to_ymd(to.year(), to.month() as u8, to.day() as u8);
+                }
+                let (total, items) = client.fetch_page(&params, page).await?;
+                (total, CrawlData::MealService(items))
+            }
+        };
+
+        Ok(Some(page))
+    }
+}
+
+/// 수집 단위 하나, (교육청, 학교, API)
+struct Unit<'s> {
+    /// 결과의 위치
+    office: usize,
+    school: &'s SchoolInfoItem,
+    endpoint: CrawlEndpoint,
+    /// 시작할 때의 체크포인트
+    progress: Progress,
+}
+
+struct UnitState<'s> {
+    unit: Unit<'s>,
+    page: usize,
+    total: Option<usize>,
+    restarted: bool,
+    outcome: Outcome,
+}
+
+impl<'s> UnitState<'s> {
+    fn done(self) -> Event<'s> {
+        Event::Done {
+            office: self.unit.office,
+            outcome: self.outcome,
+        }
+    }
+}
+
+enum Event<'s> {
+    /// 받은 페이지, 콜백에 넘긴 뒤 체크포인트에 기록한다.
+    Page {
+        school: &'s SchoolInfoItem,
+        endpoint: CrawlEndpoint,
+        page: usize,
+        total: usize,
+        data: CrawlData,
+    },
+    /// 전체 건수가 바뀌어 체크포인트를 지우고 첫 페이지부터 다시 받는다.
+    Restart {
+        school: &'s SchoolInfoItem,
+        endpoint: CrawlEndpoint,
+    },
+    Done {
+        office: usize,
+        outcome: Outcome,
+    },
+}
+
+#[derive(Debug, Default)]
+struct Outcome {
+    pages: usize,
+    resumed_pages: usize,
+    rows: usize,
+    unsupported: bool,
+    failure: Option<CrawlFailure>,
+}
@@ -1,12 +1,34 @@
 use hyper::Error as HyperError;
 use hyper_util::client::legacy::Error as ClientError;
 use serde_json::Error as JsonError;
+use std::sync::Arc;
 
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum Error {
     Hyper(HyperError),
     Client(ClientError),
     Json(JsonError),
+    Io(std::io::Error),
+    #[cfg(feature = "csv")]
+    Csv(csv::Error),
+    #[cfg(feature = "arrow")]
+    Arrow(arrow_schema::ArrowError),
+    #[cfg(feature = "parquet")]
+    Parquet(parquet::errors::ParquetError),
+    #[cfg(feature = "sync")]
+    Sqlite(rusqlite::Error),
+    /// 시간표 API 가 없는 학교종류
+    UnsupportedSchoolKind(String),
+    /// 제한 시간 안에 응답이 없음
+    Timeout(std::time::Duration),
+    /// API 가 돌려준 오류 결과 코드
+    Api {
+        code: String,
+        message: String,
+    },
+    /// 여러 요청이 함께 기다린 요청의 오류 중 복제할 수 없는 것
+    Shared(Arc<Error>),
     Unknown(String),
 }
 
@@ -14,6 +36,25 @@ impl Error {
     pub fn new_unknown(message: &str) -> Error {
         Error::Unknown(message.to_owned())
     }
+
+    /// 함께 기다린 요청의 오류를 되도록 원래 종류로 되돌림
+    pub(crate) fn from_shared(err: Arc<Error>) -> Error {
+        let err = match Arc::try_unwrap(err) {
+            Ok(err) => return err,
+            Err(err) => err,
+        };
+        match &*err {
+            Error::Io(io) => Error::Io(std::io::Error::new(io.kind(), io.to_string())),
+            Error::UnsupportedSchoolKind(kind) => Error::UnsupportedSchoolKind(kind.clone()),
+            Error::Timeout(timeout) => Error::Timeout(*timeout),
+            Error::Api { code, message } => Error::Api {
+                code: code.clone(),
+                message: message.clone(),
+            },
+            Error::Unknown(message) => Error::Unknown(message.clone()),
+            _ => Error::Shared(err),
+        }
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -22,6 +63,24 @@ impl std::fmt::Display for Error {
             Error::Hyper(ref err) => err.fmt(f),
             Error::Client(ref err) => err.fmt(f),
             Error::Json(ref err) => err.fmt(f),
+            Error::Io(ref err) => err.fmt(f),
+            #[cfg(feature = "csv")]
+            Error::Csv(ref err) => err.fmt(f),
+            #[cfg(feature = "arrow")]
+            Error::Arrow(ref err) => err.fmt(f),
+            #[cfg(feature = "parquet")]
+            Error::Parquet(ref err) => err.fmt(f),
+            #[cfg(feature = "sync")]
+            Error::Sqlite(ref err) => err.fmt(f),
+            Error::UnsupportedSchoolKind(ref kind) => {
+                write!(f, "no timetable api for school kind: {}", kind)
+            }
+            Error::Timeout(ref timeout) => write!(f, "timed out after {:?}", timeout),
+            Error::Api {
+                ref code,
+                ref message,
+            } => write!(f, "{}: {}", code, message),
+            Error::Shared(ref err) => err.fmt(f),
             Error::Unknown(ref err) => err.fmt(f),
         }
     }
@@ -44,3 +103,37 @@ impl From<JsonError> for Error {
         Error::Json(err)
     }
 }
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err)
+    }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for Error {
+    fn from(err: csv::Error) -> Error {
+        Error::Csv(err)
+    }
+}
+
+#[cfg(feature = "arrow")]
+impl From<arrow_schema::ArrowError> for Error {
+    fn from(err: arrow_schema::ArrowError) -> Error {
+        Error::Arrow(err)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl From<parquet::errors::ParquetError> for Error {
+    fn from(err: parquet::errors::ParquetError) -> Error {
+        Error::Parquet(err)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl From<rusqlite::Error> for Error {
+    fn from(err: rusqlite::Error) -> Error {
+        Error::Sqlite(err)
+    }
+}
@@ -0,0 +1,250 @@
+//! 응답 항목 Apache Arrow `RecordBatch` 변환
+//!
+//! 일자(`*_YMD`)는 `Date32`, 수정일자(`LOAD_DTM`)는 Asia/Seoul 시간대의 `Timestamp(Second)`,
+//! 여부(`*_YN`)는 `Boolean` 열이 된다.
+//! 값이 없거나 빈 문자열인 `Option` 필드와 변환할 수 없는 일자, `Y`/`N` 이 아닌 여부는 null 이 된다.
+
+use super::common::parse_load_dtm;
+use crate::Error;
+use crate::types::{AcademyInfoItem, MealServiceItem, SchoolInfoItem, YesOrNo, parse_ymd};
+use arrow_array::types::Date32Type;
+use arrow_array::{
+    ArrayRef, BooleanArray, Date32Array, Int32Array, Int64Array, StringArray, TimestampSecondArray,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use chrono::TimeDelta;
+use std::sync::Arc;
+
+pub use arrow_array::RecordBatch;
+
+/// `RecordBatch` 로 변환할 수 있는 응답 항목
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::export::arrow::ToRecordBatch;
+/// use neis_client::types::MealServiceItem;
+/// use neis_client::Error;
+///
+/// fn print_schema(items: &[MealServiceItem]) -> Result<(), Error> {
+///     let batch = MealServiceItem::to_record_batch(items)?;
+///     println!("{} rows, {:?}", batch.num_rows(), batch.schema());
+///     Ok(())
+/// }
+/// ```
+pub trait ToRecordBatch: Sized {
+    fn to_record_batch(items: &[Self]) -> Result<RecordBatch, Error>;
+}
+
+impl ToRecordBatch for SchoolInfoItem {
+    fn to_record_batch(items: &[Self]) -> Result<RecordBatch, Error> {
+        Columns::default()
+            .utf8(
+                "ATPT_OFCDC_SC_CODE",
+                items.iter().map(|i| &i.ATPT_OFCDC_SC_CODE),
+            )
+            .utf8(
+                "ATPT_OFCDC_SC_NM",
+                items.iter().map(|i| &i.ATPT_OFCDC_SC_NM),
+            )
+            .utf8("SD_SCHUL_CODE", items.iter().map(|i| &i.SD_SCHUL_CODE))
+            .utf8("SCHUL_NM", items.iter().map(|i| &i.SCHUL_NM))
+            .nullable_utf8("ENG_SCHUL_NM", items.iter().map(|i| &i.ENG_SCHUL_NM))
+            .nullable_utf8("SCHUL_KND_SC_NM", items.iter().map(|i| &i.SCHUL_KND_SC_NM))
+            .utf8("LCTN_SC_NM", items.iter().map(|i| &i.LCTN_SC_NM))
+            .utf8("JU_ORG_NM", items.iter().map(|i| &i.JU_ORG_NM))
+            .nullable_utf8("FOND_SC_NM", items.iter().map(|i| &i.FOND_SC_NM))
+            .nullable_utf8("ORG_RDNZC", items.iter().map(|i| &i.ORG_RDNZC))
+            .nullable_utf8("ORG_RDNMA", items.iter().map(|i| &i.ORG_RDNMA))
+            .nullable_utf8("ORG_RDNDA", items.iter().map(|i| &i.ORG_RDNDA))
+            .nullable_utf8("ORG_TELNO", items.iter().map(|i| &i.ORG_TELNO))
+            .nullable_utf8("HMPG_ADRES", items.iter().map(|i| &i.HMPG_ADRES))
+            .utf8("COEDU_SC_NM", items.iter().map(|i| &i.COEDU_SC_NM))
+            .nullable_utf8("ORG_FAXNO", items.iter().map(|i| &i.ORG_FAXNO))
+            .nullable_utf8("HS_SC_NM", items.iter().map(|i| &i.HS_SC_NM))
+            .boolean(
+                "INDST_SPECL_CCCCL_EXST_YN",
+                items.iter().map(|i| &i.INDST_SPECL_CCCCL_EXST_YN),
+            )
+            .nullable_utf8(
+                "HS_GNRL_BUSNS_SC_NM",
+                items.iter().map(|i| &i.HS_GNRL_BUSNS_SC_NM),
+            )
+            .nullable_utf8(
+                "SPCLY_PURPS_HS_ORD_NM",
+                items.iter().map(|i| &i.SPCLY_PURPS_HS_ORD_NM),
+            )
+            .utf8(
+                "ENE_BFE_SEHF_SC_NM",
+                items.iter().map(|i| &i.ENE_BFE_SEHF_SC_NM),
+            )
+            .utf8("DGHT_SC_NM", items.iter().map(|i| &i.DGHT_SC_NM))
+            .date("FOND_YMD", items.iter().map(|i| &i.FOND_YMD))
+            .date("FOAS_MEMRD", items.iter().map(|i| &i.FOAS_MEMRD))
+            .timestamp("LOAD_DTM", items.iter().map(|i| &i.LOAD_DTM))
+            .finish()
+    }
+}
+
+impl ToRecordBatch for AcademyInfoItem {
+    fn to_record_batch(items: &[Self]) -> Result<RecordBatch, Error> {
+        Columns::default()
+            .utf8(
+                "ATPT_OFCDC_SC_CODE",
+                items.iter().map(|i| &i.ATPT_OFCDC_SC_CODE),
+            )
+            .utf8(
+                "ATPT_OFCDC_SC_NM",
+                items.iter().map(|i| &i.ATPT_OFCDC_SC_NM),
+            )
+            .nullable_utf8("ADMST_ZONE_NM", items.iter().map(|i| &i.ADMST_ZONE_NM))
+            .utf8("ACA_INSTI_SC_NM", items.iter().map(|i| &i.ACA_INSTI_SC_NM))
+            .utf8("ACA_ASNUM", items.iter().map(|i| &i.ACA_ASNUM))
+            .utf8("ACA_NM", items.iter().map(|i| &i.ACA_NM))
+            .date("ESTBL_YMD", items.iter().map(|i| &i.ESTBL_YMD))
+            .date("REG_YMD", items.iter().map(|i| &i.REG_YMD))
+            .utf8("REG_STTUS_NM", items.iter().map(|i| &i.REG_STTUS_NM))
+            .date("CAA_BEGIN_YMD", items.iter().map(|i| &i.CAA_BEGIN_YMD))
+            .date("CAA_END_YMD", items.iter().map(|i| &i.CAA_END_YMD))
+            .int64("TOFOR_SMTOT", items.iter().map(|i| i.TOFOR_SMTOT))
+            .int64(
+                "DTM_RCPTN_ABLTY_NMPR_SMTOT",
+                items.iter().map(|i| i.DTM_RCPTN_ABLTY_NMPR_SMTOT),
+            )
+            .nullable_utf8("REALM_SC_NM", items.iter().map(|i| &i.REALM_SC_NM))
+            .nullable_utf8("LE_ORD_NM", items.iter().map(|i| &i.LE_ORD_NM))
+            .nullable_utf8("LE_CRSE_LIST_NM", items.iter().map(|i| &i.LE_CRSE_LIST_NM))
+            .nullable_utf8("LE_CRSE_NM", items.iter().map(|i| &i.LE_CRSE_NM))
+            .utf8(
+                "PSNBY_THCC_CNTNT",
+                items.iter().map(|i| &i.PSNBY_THCC_CNTNT),
+            )
+            .boolean("THCC_OTHBC_YN", items.iter().map(|i| &i.THCC_OTHBC_YN))
+            .nullable_boolean("BRHS_ACA_YN", items.iter().map(|i| &i.BRHS_ACA_YN))
+            .utf8("FA_RDNMA", items.iter().map(|i| &i.FA_RDNMA))
+            .utf8("FA_RDNDA", items.iter().map(|i| &i.FA_RDNDA))
+            .utf8("FA_RDNZC", items.iter().map(|i| &i.FA_RDNZC))
+            .nullable_utf8("FA_TELNO", items.iter().map(|i| &i.FA_TELNO))
+            .timestamp("LOAD_DTM", items.iter().map(|i| &i.LOAD_DTM))
+            .finish()
+    }
+}
+
+impl ToRecordBatch for MealServiceItem {
+    fn to_record_batch(items: &[Self]) -> Result<RecordBatch, Error> {
+        Columns::default()
+            .utf8(
+                "ATPT_OFCDC_SC_CODE",
+                items.iter().map(|i| &i.ATPT_OFCDC_SC_CODE),
+            )
+            .utf8(
+                "ATPT_OFCDC_SC_NM",
+                items.iter().map(|i| &i.ATPT_OFCDC_SC_NM),
+            )
+            .utf8("SD_SCHUL_CODE", items.iter().map(|i| &i.SD_SCHUL_CODE))
+            .utf8("SCHUL_NM", items.iter().map(|i| &i.SCHUL_NM))
+            .utf8("MMEAL_SC_CODE", items.iter().map(|i| &i.MMEAL_SC_CODE))
+            .utf8("MMEAL_SC_NM", items.iter().map(|i| &i.MMEAL_SC_NM))
+            .date("MLSV_YMD", items.iter().map(|i| &i.MLSV_YMD))
+            .int32("MLSV_FGR", items.iter().map(|i| i.MLSV_FGR))
+            .utf8("DDISH_NM", items.iter().map(|i| &i.DDISH_NM))
+            .utf8("ORPLC_INFO", items.iter().map(|i| &i.ORPLC_INFO))
+            .nullable_utf8("CAL_INFO", items.iter().map(|i| &i.CAL_INFO))
+            .nullable_utf8("NTR_INFO", items.iter().map(|i| &i.NTR_INFO))
+            .date("MLSV_FROM_YMD", items.iter().map(|i| &i.MLSV_FROM_YMD))
+            .date("MLSV_TO_YMD", items.iter().map(|i| &i.MLSV_TO_YMD))
+            .timestamp("LOAD_DTM", items.iter().map(|i| &i.LOAD_DTM))
+            .finish()
+    }
+}
+
+#[derive(Default)]
+struct Columns {
+    fields: Vec<Field>,
+    arrays: Vec<ArrayRef>,
+}
+
+impl Columns {
+    fn push(mut self, field: Field, array: ArrayRef) -> Self {
+        self.fields.push(field);
+        self.arrays.push(array);
+        self
+    }
+
+    fn utf8<'a>(self, name: &str, values: impl Iterator<Item = &'a String>) -> Self {
+        let array = StringArray::from_iter_values(values);
+        self.push(Field::new(name, DataType::Utf8, false), Arc::new(array))
+    }
+
+    fn nullable_utf8<'a>(
+        self,
+        name: &str,
+        values: impl Iterator<Item = &'a Option<String>>,
+    ) -> Self {
+        let array: StringArray = values
+            .map(|v| v.as_deref().map(str::trim).filter(|s| !s.is_empty()))
+            .collect();
+        self.push(Field::new(name, DataType::Utf8, true), Arc::new(array))
+    }
+
+    fn int32(self, name: &str, values: impl Iterator<Item = i32>) -> Self {
+        let array = Int32Array::from_iter_values(values);
+        self.push(Field::new(name, DataType::Int32, false), Arc::new(array))
+    }
+
+    fn int64(self, name: &str, values: impl Iterator<Item = i64>) -> Self {
+        let array = Int64Array::from_iter_values(values);
+        self.push(Field::new(name, DataType::Int64, false), Arc::new(array))
+    }
+
+    fn boolean<'a>(self, name: &str, values: impl Iterator<Item = &'a YesOrNo>) -> Self {
+        let array: BooleanArray = values.map(|v| Some(*v == YesOrNo::Y)).collect();
+        self.push(Field::new(name, DataType::Boolean, false), Arc::new(array))
+    }
+
+    /// 문자열로 오는 Y/N
+    fn nullable_boolean<'a>(self, name: &str, values: impl Iterator<Item = &'a String>) -> Self {
+        let array: BooleanArray = values
+            .map(|v| match v.trim() {
+                "Y" => Some(true),
+                "N" => Some(false),
+                _ => None,
+            })
+            .collect();
+        self.push(Field::new(name, DataType::Boolean, true), Arc::new(array))
+    }
+
+    /// YYYYMMDD
+    fn date<'a>(self, name: &str, values: impl Iterator<Item = &'a String>) -> Self {
+        let array: Date32Array = values
+            .map(|v| parse_ymd(v).map(Date32Type::from_naive_date))
+            .collect();
+        self.push(Field::new(name, DataType::Date32, true), Arc::new(array))
+    }
+
+    /// 한국 표준시 YYYYMMDD 또는 YYYYMMDDHHMMSS
+    fn timestamp<'a>(self, name: &str, values: impl Iterator<Item = &'a String>) -> Self {
+        let array: TimestampSecondArray = values
+            .map(|v| {
+                let local = parse_load_dtm(v)?;
+                Some((local - TimeDelta::hours(9)).and_utc().timestamp())
+            })
+            .collect();
+        let array = array.with_timezone(TIMEZONE);
+        self.push(
+            Field::new(
+                name,
+                DataType::Timestamp(TimeUnit::Second, Some(TIMEZONE.into())),
+                true,
+            ),
+            Arc::new(array),
+        )
+    }
+
+    fn finish(self) -> Result<RecordBatch, Error> {
+        let schema = Arc::new(Schema::new(self.fields));
+        Ok(RecordBatch::try_new(schema, self.arrays)?)
+    }
+}
+
+const TIMEZONE: &str = "Asia/Seoul";
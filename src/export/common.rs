@@ -0,0 +1,45 @@
+#[cfg(any(feature = "ical", feature = "feed"))]
+use crate::types::MealServiceItem;
+use crate::types::parse_ymd;
+use chrono::NaiveDateTime;
+
+/// 고정된 값들로 만드는 UID
+#[cfg(any(feature = "ical", feature = "feed"))]
+pub(super) fn uid(parts: &[&str]) -> String {
+    format!("{}@open.neis.go.kr", parts.join("-"))
+}
+
+/// 수정일자(LOAD_DTM)를 변환
+/// YYYYMMDD 또는 YYYYMMDDHHMMSS 형식
+pub(super) fn parse_load_dtm(load_dtm: &str) -> Option<NaiveDateTime> {
+    let load_dtm = load_dtm.trim();
+    NaiveDateTime::parse_from_str(load_dtm, "%Y%m%d%H%M%S")
+        .ok()
+        .or_else(|| Some(parse_ymd(load_dtm)?.and_time(Default::default())))
+}
+
+/// 요리별 한 줄과 칼로리정보
+#[cfg(any(feature = "ical", feature = "feed"))]
+pub(super) fn meal_lines(item: &MealServiceItem) -> Vec<String> {
+    let mut lines: Vec<String> = item
+        .dishes()
+        .iter()
+        .map(|dish| {
+            let allergens = dish.allergen_names();
+            if allergens.is_empty() {
+                dish.name.clone()
+            } else {
+                format!("{} ({})", dish.name, allergens.join(", "))
+            }
+        })
+        .collect();
+    if let Some(cal_info) = item
+        .CAL_INFO
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    {
+        lines.push(format!("칼로리: {}", cal_info));
+    }
+    lines
+}
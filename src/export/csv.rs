@@ -0,0 +1,93 @@
+//! 응답 항목 CSV 내보내기
+
+use crate::Error;
+use crate::types::FieldLabels;
+use serde::Serialize;
+use std::io;
+
+/// CSV 머리글 형식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Header {
+    /// NEIS 필드명
+    /// Example: SCHUL_NM
+    #[default]
+    Raw,
+    /// 한글 이름
+    /// Example: 학교명
+    Korean,
+}
+
+/// 응답 항목 목록을 CSV 로 쓴다.
+///
+/// 값이 없는 필드는 빈 칸이 된다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::export::csv::{CsvExport, Header};
+/// use neis_client::types::ClassInfoItem;
+/// use neis_client::Error;
+///
+/// fn save(items: &[ClassInfoItem]) -> Result<(), Error> {
+///     let csv = CsvExport::new().header(Header::Korean).to_string(items)?;
+///     std::fs::write("classes.csv", csv)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CsvExport {
+    header: Header,
+    bom: bool,
+}
+
+impl CsvExport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header(mut self, header: Header) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// UTF-8 BOM 을 앞에 붙인다.
+    /// Excel 에서 한글이 깨지지 않게 열려면 필요
+    pub fn bom(mut self, bom: bool) -> Self {
+        self.bom = bom;
+        self
+    }
+
+    pub fn write<W, T>(&self, mut writer: W, items: &[T]) -> Result<(), Error>
+    where
+        W: io::Write,
+        T: Serialize + FieldLabels,
+    {
+        if self.bom {
+            writer
+                .write_all("\u{feff}".as_bytes())
+                .map_err(::csv::Error::from)?;
+        }
+
+        let mut writer = ::csv::WriterBuilder::new()
+            .has_headers(false)
+            .from_writer(writer);
+        writer.write_record(T::FIELDS.iter().map(|(raw, label)| match self.header {
+            Header::Raw => *raw,
+            Header::Korean => *label,
+        }))?;
+        for item in items {
+            writer.serialize(item)?;
+        }
+        writer.flush().map_err(::csv::Error::from)?;
+        Ok(())
+    }
+
+    pub fn to_string<T>(&self, items: &[T]) -> Result<String, Error>
+    where
+        T: Serialize + FieldLabels,
+    {
+        let mut buf = Vec::new();
+        self.write(&mut buf, items)?;
+        String::from_utf8(buf).map_err(|e| Error::new_unknown(&e.to_string()))
+    }
+}
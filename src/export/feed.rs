@@ -0,0 +1,150 @@
+//! 급식식단 RSS 2.0, Atom 피드 내보내기
+
+use super::common::{meal_lines, parse_load_dtm, uid};
+use crate::types::{MealServiceItem, parse_ymd};
+use chrono::NaiveDateTime;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// 급식식단 피드
+///
+/// 식사마다 하나의 글이 되며, 최근 급식일자가 먼저 온다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::export::feed::MealFeed;
+/// use neis_client::types::MealServiceItem;
+///
+/// fn save(items: &[MealServiceItem]) -> std::io::Result<()> {
+///     let feed = MealFeed::new("문현고등학교 급식", "https://munhyeon.sen.hs.kr");
+///     std::fs::write("meals.xml", feed.to_rss(items))
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MealFeed {
+    title: String,
+    link: String,
+}
+
+impl MealFeed {
+    pub fn new(title: &str, link: &str) -> Self {
+        Self {
+            title: title.to_owned(),
+            link: link.to_owned(),
+        }
+    }
+
+    /// RSS 2.0
+    pub fn to_rss(&self, items: &[MealServiceItem]) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<rss version=\"2.0\">\n<channel>\n");
+        let _ = writeln!(xml, "<title>{}</title>", escape(&self.title));
+        let _ = writeln!(xml, "<link>{}</link>", escape(&self.link));
+        let _ = writeln!(xml, "<description>{}</description>", escape(&self.title));
+        xml.push_str("<language>ko</language>\n");
+
+        for entry in entries(items) {
+            xml.push_str("<item>\n");
+            let _ = writeln!(xml, "<title>{}</title>", escape(&entry.title));
+            let _ = writeln!(xml, "<link>{}</link>", escape(&self.link));
+            let _ = writeln!(
+                xml,
+                "<guid isPermaLink=\"false\">{}</guid>",
+                escape(&entry.uid)
+            );
+            let _ = writeln!(
+                xml,
+                "<pubDate>{}</pubDate>",
+                entry.updated.format("%a, %d %b %Y %H:%M:%S +0900")
+            );
+            let _ = writeln!(xml, "<description>{}</description>", escape(&entry.html));
+            xml.push_str("</item>\n");
+        }
+
+        xml.push_str("</channel>\n</rss>\n");
+        xml
+    }
+
+    /// Atom 1.0
+    pub fn to_atom(&self, items: &[MealServiceItem]) -> String {
+        let entries = entries(items);
+        let updated = entries.iter().map(|e| e.updated).max().unwrap_or_default();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\" xml:lang=\"ko\">\n");
+        let _ = writeln!(xml, "<title>{}</title>", escape(&self.title));
+        let _ = writeln!(xml, "<link href=\"{}\"/>", escape(&self.link));
+        let _ = writeln!(xml, "<id>{}</id>", escape(&self.link));
+        let _ = writeln!(xml, "<updated>{}</updated>", rfc3339(&updated));
+
+        for entry in &entries {
+            xml.push_str("<entry>\n");
+            let _ = writeln!(xml, "<title>{}</title>", escape(&entry.title));
+            let _ = writeln!(xml, "<link href=\"{}\"/>", escape(&self.link));
+            let _ = writeln!(xml, "<id>urn:neis:{}</id>", escape(&entry.uid));
+            let _ = writeln!(xml, "<updated>{}</updated>", rfc3339(&entry.updated));
+            let _ = writeln!(
+                xml,
+                "<content type=\"html\">{}</content>",
+                escape(&entry.html)
+            );
+            xml.push_str("</entry>\n");
+        }
+
+        xml.push_str("</feed>\n");
+        xml
+    }
+}
+
+struct Entry {
+    uid: String,
+    title: String,
+    html: String,
+    updated: NaiveDateTime,
+}
+
+fn entries(items: &[MealServiceItem]) -> Vec<Entry> {
+    let mut items: Vec<&MealServiceItem> = items.iter().collect();
+    items.sort_by(|a, b| (&b.MLSV_YMD, &b.MMEAL_SC_CODE).cmp(&(&a.MLSV_YMD, &a.MMEAL_SC_CODE)));
+
+    let mut seen = HashSet::new();
+    let mut entries = Vec::new();
+    for item in items {
+        let Some(date) = parse_ymd(&item.MLSV_YMD) else {
+            continue;
+        };
+        let uid = uid(&[&item.SD_SCHUL_CODE, &item.MLSV_YMD, &item.MMEAL_SC_CODE]);
+        if !seen.insert(uid.clone()) {
+            continue;
+        }
+
+        let lines: Vec<String> = meal_lines(item).iter().map(|l| escape(l)).collect();
+        entries.push(Entry {
+            uid,
+            title: format!("{} {}", date.format("%Y-%m-%d"), item.MMEAL_SC_NM),
+            html: lines.join("<br/>"),
+            updated: parse_load_dtm(&item.LOAD_DTM).unwrap_or(date.and_time(Default::default())),
+        });
+    }
+    entries
+}
+
+fn rfc3339(datetime: &NaiveDateTime) -> String {
+    format!("{}+09:00", datetime.format("%Y-%m-%dT%H:%M:%S"))
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
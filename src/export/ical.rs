@@ -0,0 +1,358 @@
+//! RFC 5545 iCalendar 내보내기
+
+use super::common::{meal_lines, parse_load_dtm, uid};
+use crate::timetable::{BellSchedule, TimetableEntry};
+use crate::types::{MealServiceItem, SchoolScheduleItem, parse_ymd};
+use chrono::{Days, NaiveDate, NaiveDateTime, TimeDelta};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+
+const PRODID: &str = "-//rubymix//neis-client//KO";
+const TZID: &str = "Asia/Seoul";
+
+/// 일정의 시작, 종료 시각
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum EventTime {
+    /// 종일 일정
+    Date(NaiveDate),
+    /// 한국 표준시 기준 일시
+    DateTime(NaiveDateTime),
+}
+
+/// VEVENT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Event {
+    /// 다시 내보내도 바뀌지 않는 고유 식별자
+    pub uid: String,
+    /// 한국 표준시 기준 수정일시
+    pub stamp: NaiveDateTime,
+    pub start: EventTime,
+    /// 종일 일정은 종료일 다음날
+    pub end: EventTime,
+    pub summary: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub categories: Vec<String>,
+}
+
+/// VCALENDAR
+///
+/// `to_string()` 으로 .ics 파일 내용을 만든다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::export::ical::Calendar;
+/// use neis_client::types::SchoolScheduleItem;
+///
+/// fn save(items: &[SchoolScheduleItem]) -> std::io::Result<()> {
+///     let ics = Calendar::from_school_schedule(items).to_string();
+///     std::fs::write("schedule.ics", ics)
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Calendar {
+    name: Option<String>,
+    events: Vec<Event>,
+}
+
+impl Calendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 캘린더 앱에 표시되는 이름 (X-WR-CALNAME)
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// 같은 UID 의 일정이 이미 있으면 무시
+    pub fn push(&mut self, event: Event) {
+        if !self.events.iter().any(|e| e.uid == event.uid) {
+            self.events.push(event);
+        }
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// 학사일정을 종일 일정으로 변환
+    ///
+    /// UID 는 행정표준코드, 학사일자, 행사명으로 만든다.
+    /// 주야과정별로 중복된 행사는 하나로 합친다.
+    pub fn from_school_schedule(items: &[SchoolScheduleItem]) -> Self {
+        let mut calendar = Self::new();
+        if let Some(item) = items.first() {
+            calendar = calendar.name(&format!("{} 학사일정", item.SCHUL_NM));
+        }
+
+        let mut seen = HashSet::new();
+        for item in items {
+            let Some(date) = parse_ymd(&item.AA_YMD) else {
+                continue;
+            };
+            let summary = item.EVENT_NM.trim();
+            let uid = uid(&[&item.SD_SCHUL_CODE, &item.AA_YMD, &hash(summary)]);
+            if !seen.insert(uid.clone()) {
+                continue;
+            }
+
+            let description = Some(item.EVENT_CNTNT.trim())
+                .filter(|s| !s.is_empty())
+                .map(str::to_owned);
+            calendar.events.push(Event {
+                uid,
+                stamp: parse_load_dtm(&item.LOAD_DTM).unwrap_or(date.and_time(Default::default())),
+                start: EventTime::Date(date),
+                end: EventTime::Date(date + Days::new(1)),
+                summary: summary.to_owned(),
+                description,
+                location: None,
+                categories: item.grades().iter().map(|g| format!("{}학년", g)).collect(),
+            });
+        }
+        calendar
+    }
+
+    /// 한 학급의 시간표를 일과표 시각에 맞춘 일정으로 변환
+    ///
+    /// UID 는 행정표준코드, 시간표일자, 학년, 학급명, 교시로 만들어 수업내용이 바뀌어도
+    /// 캘린더 앱에서 같은 일정으로 갱신된다.
+    /// 한 교시에 여러 수업이 있으면 하나의 일정으로 합치고(같은 수업은 한 번만),
+    /// 일과표에 없는 교시는 제외한다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::export::ical::Calendar;
+    /// use neis_client::timetable::BellSchedule;
+    /// use neis_client::types::HisTimetableItem;
+    ///
+    /// fn save(items: &[HisTimetableItem]) -> std::io::Result<()> {
+    ///     let ics = Calendar::from_timetable(items, &BellSchedule::high()).to_string();
+    ///     std::fs::write("timetable.ics", ics)
+    /// }
+    /// ```
+    pub fn from_timetable<E: TimetableEntry>(entries: &[E], bell: &BellSchedule) -> Self {
+        let mut calendar = Self::new();
+        if let Some(entry) = entries.first() {
+            calendar = calendar.name(&format!(
+                "{} {}학년 {}반 시간표",
+                entry.school_name(),
+                entry.grade(),
+                entry.class_nm().unwrap_or_default()
+            ));
+        }
+
+        let mut slots: BTreeMap<(&str, &str, u8, &str, u8), Vec<&E>> = BTreeMap::new();
+        for entry in entries {
+            let key = (
+                entry.school_code(),
+                entry.ymd(),
+                entry.grade(),
+                entry.class_nm().unwrap_or_default(),
+                entry.perio(),
+            );
+            slots.entry(key).or_default().push(entry);
+        }
+
+        for ((school_code, ymd, grade, class_nm, perio), entries) in slots {
+            let Some((start, end)) = entries[0].times(bell) else {
+                tracing::debug!(ymd, perio, "no bell time for period");
+                continue;
+            };
+
+            // 같은 행이 중복으로 오는 경우가 있어 한 번만 넣음
+            let mut lessons = Vec::new();
+            for lesson in entries.iter().filter_map(|e| e.lesson()) {
+                if !lessons.contains(&lesson) {
+                    lessons.push(lesson);
+                }
+            }
+            let summary: Vec<&str> = lessons.iter().map(|l| l.subject.as_str()).collect();
+            let mut tags: Vec<&str> = Vec::new();
+            for tag in lessons
+                .iter()
+                .flat_map(|l| l.tags.iter().map(String::as_str))
+            {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            let mut rooms: Vec<&str> = Vec::new();
+            for room in entries.iter().filter_map(|e| e.classroom()).map(str::trim) {
+                if !room.is_empty() && !rooms.contains(&room) {
+                    rooms.push(room);
+                }
+            }
+            let stamp = entries
+                .iter()
+                .filter_map(|e| parse_load_dtm(e.load_dtm()))
+                .max()
+                .unwrap_or(start);
+
+            calendar.events.push(Event {
+                uid: uid(&[
+                    school_code,
+                    ymd,
+                    &grade.to_string(),
+                    class_nm,
+                    &perio.to_string(),
+                ]),
+                stamp,
+                start: EventTime::DateTime(start),
+                end: EventTime::DateTime(end),
+                summary: summary.join(" / "),
+                description: Some(tags.join(", ")).filter(|s| !s.is_empty()),
+                location: Some(rooms.join(", ")).filter(|s| !s.is_empty()),
+                categories: vec![format!("{}교시", perio)],
+            });
+        }
+        calendar
+    }
+
+    /// 급식식단을 식사별 종일 일정으로 변환
+    ///
+    /// 요리와 알레르기 유발 식품, 칼로리정보를 설명에 넣는다.
+    /// UID 는 행정표준코드, 급식일자, 식사코드로 만든다.
+    pub fn from_meal_service(items: &[MealServiceItem]) -> Self {
+        let mut calendar = Self::new();
+        if let Some(item) = items.first() {
+            calendar = calendar.name(&format!("{} 급식", item.SCHUL_NM));
+        }
+
+        for item in items {
+            let Some(date) = parse_ymd(&item.MLSV_YMD) else {
+                continue;
+            };
+            calendar.push(Event {
+                uid: uid(&[&item.SD_SCHUL_CODE, &item.MLSV_YMD, &item.MMEAL_SC_CODE]),
+                stamp: parse_load_dtm(&item.LOAD_DTM).unwrap_or(date.and_time(Default::default())),
+                start: EventTime::Date(date),
+                end: EventTime::Date(date + Days::new(1)),
+                summary: item.MMEAL_SC_NM.clone(),
+                description: Some(meal_lines(item).join("\n")),
+                location: None,
+                categories: vec![item.MMEAL_SC_NM.clone()],
+            });
+        }
+        calendar
+    }
+}
+
+impl std::fmt::Display for Calendar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut lines = vec![
+            String::from("BEGIN:VCALENDAR"),
+            String::from("VERSION:2.0"),
+            format!("PRODID:{}", PRODID),
+            String::from("CALSCALE:GREGORIAN"),
+        ];
+        if let Some(name) = &self.name {
+            lines.push(format!("X-WR-CALNAME:{}", escape(name)));
+        }
+        lines.push(format!("X-WR-TIMEZONE:{}", TZID));
+
+        let timed = self
+            .events
+            .iter()
+            .any(|e| matches!(e.start, EventTime::DateTime(_)));
+        if timed {
+            lines.extend(
+                [
+                    "BEGIN:VTIMEZONE",
+                    "TZID:Asia/Seoul",
+                    "BEGIN:STANDARD",
+                    "DTSTART:19700101T000000",
+                    "TZOFFSETFROM:+0900",
+                    "TZOFFSETTO:+0900",
+                    "TZNAME:KST",
+                    "END:STANDARD",
+                    "END:VTIMEZONE",
+                ]
+                .map(String::from),
+            );
+        }
+
+        for event in &self.events {
+            lines.push(String::from("BEGIN:VEVENT"));
+            lines.push(format!("UID:{}", event.uid));
+            // DTSTAMP 는 UTC 로 표기
+            let stamp = event.stamp - TimeDelta::hours(9);
+            lines.push(format!("DTSTAMP:{}", stamp.format("%Y%m%dT%H%M%SZ")));
+            lines.push(format_time("DTSTART", &event.start));
+            lines.push(format_time("DTEND", &event.end));
+            lines.push(format!("SUMMARY:{}", escape(&event.summary)));
+            if let Some(description) = &event.description {
+                lines.push(format!("DESCRIPTION:{}", escape(description)));
+            }
+            if let Some(location) = &event.location {
+                lines.push(format!("LOCATION:{}", escape(location)));
+            }
+            if !event.categories.is_empty() {
+                let categories: Vec<String> = event.categories.iter().map(|c| escape(c)).collect();
+                lines.push(format!("CATEGORIES:{}", categories.join(",")));
+            }
+            lines.push(String::from("END:VEVENT"));
+        }
+        lines.push(String::from("END:VCALENDAR"));
+
+        for line in lines {
+            write_folded(f, &line)?;
+        }
+        Ok(())
+    }
+}
+
+/// 행사명처럼 길이가 일정하지 않은 값을 UID 에 넣을 때 사용
+// Rust 버전이 바뀌어도 값이 같아야 하므로 std Hasher 대신 FNV-1a 사용
+fn hash(text: &str) -> String {
+    let hash = text.bytes().fold(0xcbf29ce484222325_u64, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", hash)
+}
+
+fn format_time(name: &str, time: &EventTime) -> String {
+    match time {
+        EventTime::Date(date) => format!("{};VALUE=DATE:{}", name, date.format("%Y%m%d")),
+        EventTime::DateTime(datetime) => format!(
+            "{};TZID={}:{}",
+            name,
+            TZID,
+            datetime.format("%Y%m%dT%H%M%S")
+        ),
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// 한 줄은 75 octet 을 넘지 않도록 접음 (RFC 5545 3.1)
+fn write_folded(f: &mut std::fmt::Formatter<'_>, line: &str) -> std::fmt::Result {
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > 75 {
+            f.write_str("\r\n ")?;
+            width = 1;
+        }
+        f.write_char(c)?;
+        width += len;
+    }
+    f.write_str("\r\n")
+}
@@ -0,0 +1,12 @@
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(any(feature = "ical", feature = "feed", feature = "arrow"))]
+mod common;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "feed")]
+pub mod feed;
+#[cfg(feature = "ical")]
+pub mod ical;
+#[cfg(feature = "parquet")]
+pub mod parquet;
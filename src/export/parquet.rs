@@ -0,0 +1,50 @@
+//! 응답 항목 Parquet 내보내기
+
+use super::arrow::ToRecordBatch;
+use crate::Error;
+use ::parquet::arrow::ArrowWriter;
+use ::parquet::basic::Compression;
+use ::parquet::file::properties::WriterProperties;
+use std::io::Write;
+
+/// 한 row group 의 최대 행 수
+const ROW_GROUP_SIZE: usize = 64 * 1024;
+
+/// 응답 항목 목록을 Snappy 로 압축한 Parquet 파일 하나로 쓴다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::export::parquet;
+/// use neis_client::types::SchoolInfoItem;
+///
+/// # fn schools() -> Vec<SchoolInfoItem> { Vec::new() }
+/// let mut file = Vec::new(); // std::fs::File::create("schools.parquet")?
+/// parquet::write(&mut file, &schools()).unwrap();
+///
+/// assert_eq!(&file[..4], b"PAR1");
+/// ```
+pub fn write<W, T>(writer: W, items: &[T]) -> Result<(), Error>
+where
+    W: Write + Send,
+    T: ToRecordBatch,
+{
+    let properties = WriterProperties::builder()
+        .set_compression(Compression::SNAPPY)
+        .set_max_row_group_size(ROW_GROUP_SIZE)
+        .build();
+
+    let mut batches = items.chunks(ROW_GROUP_SIZE).map(T::to_record_batch);
+    let first = match batches.next() {
+        Some(batch) => batch?,
+        None => T::to_record_batch(&[])?,
+    };
+
+    let mut writer = ArrowWriter::try_new(writer, first.schema(), Some(properties))?;
+    writer.write(&first)?;
+    for batch in batches {
+        writer.write(&batch?)?;
+    }
+    writer.close()?;
+    Ok(())
+}
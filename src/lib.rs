@@ -1,6 +1,14 @@
+pub mod calendar;
 mod client;
+#[cfg(feature = "crawler")]
+pub mod crawler;
 mod error;
+pub mod export;
+#[cfg(feature = "sync")]
+pub mod sync;
+pub mod timetable;
 pub mod types;
+pub mod watermark;
 
-pub use client::NeisClient;
+pub use client::{KeyRotation, KeyStatus, KeyUsage, NeisClient};
 pub use error::Error;
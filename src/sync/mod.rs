@@ -0,0 +1,262 @@
+//! NEIS 데이터를 SQLite 로 미러링
+//!
+//! 데이터셋마다 하나의 테이블을 만들고, 자연키가 같은 행은 내용이 바뀐 경우에만 덮어쓴다.
+
+mod table;
+mod watermark;
+
+pub use table::Table;
+
+use crate::client::ExtractFromResponse;
+use crate::types::*;
+use crate::{Error, NeisClient};
+use rusqlite::types::Value;
+use rusqlite::{Connection, OptionalExtension, params_from_iter};
+use std::path::Path;
+
+/// 반영 결과 행 수
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// 새로 추가
+    pub inserted: usize,
+    /// 내용이 바뀌어 덮어씀
+    pub updated: usize,
+    /// 내용이 같아 건너뜀
+    pub unchanged: usize,
+}
+
+impl SyncReport {
+    pub fn total(&self) -> usize {
+        self.inserted + self.updated + self.unchanged
+    }
+}
+
+impl std::ops::AddAssign for SyncReport {
+    fn add_assign(&mut self, other: Self) {
+        self.inserted += other.inserted;
+        self.updated += other.updated;
+        self.unchanged += other.unchanged;
+    }
+}
+
+/// SQLite 미러
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::sync::SqliteMirror;
+/// use neis_client::types::MealServiceItem;
+/// use neis_client::Error;
+///
+/// fn save(items: &[MealServiceItem]) -> Result<(), Error> {
+///     let mut mirror = SqliteMirror::open("neis.db")?;
+///     mirror.create_tables()?;
+///
+///     let report = mirror.upsert(items)?;
+///     println!("추가 {}, 갱신 {}, 그대로 {}", report.inserted, report.updated, report.unchanged);
+///     Ok(())
+/// }
+/// ```
+pub struct SqliteMirror {
+    conn: Connection,
+}
+
+impl SqliteMirror {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        Ok(Self::from_connection(Connection::open(path)?))
+    }
+
+    pub fn open_in_memory() -> Result<Self, Error> {
+        Ok(Self::from_connection(Connection::open_in_memory()?))
+    }
+
+    pub fn from_connection(conn: Connection) -> Self {
+        Self { conn }
+    }
+
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+
+    /// 12개 데이터셋과 워터마크 테이블을 모두 만든다.
+    /// 이미 있는 테이블은 그대로 둔다.
+    pub fn create_tables(&self) -> Result<(), Error> {
+        self.create_table::<SchoolInfoItem>()?;
+        self.create_table::<ClassInfoItem>()?;
+        self.create_table::<SchoolMajorInfoItem>()?;
+        self.create_table::<SchoolAflcoInfoItem>()?;
+        self.create_table::<SchoolScheduleItem>()?;
+        self.create_table::<ElsTimetableItem>()?;
+        self.create_table::<MisTimetableItem>()?;
+        self.create_table::<HisTimetableItem>()?;
+        self.create_table::<SpsTimetableItem>()?;
+        self.create_table::<ClassRoomInfoItem>()?;
+        self.create_table::<AcademyInfoItem>()?;
+        self.create_table::<MealServiceItem>()?;
+        self.conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS sync_watermark (
+    ENDPOINT TEXT NOT NULL,
+    QUERY TEXT NOT NULL,
+    LOAD_DTM TEXT NOT NULL,
+    PRIMARY KEY (ENDPOINT, QUERY)
+)",
+        )?;
+        Ok(())
+    }
+
+    pub fn create_table<T: Table>(&self) -> Result<(), Error> {
+        let mut columns: Vec<String> = T::FIELDS
+            .iter()
+            .map(|(name, _)| {
+                let integer = T::INTEGERS.contains(name);
+                let mut column = format!("{} {}", name, if integer { "INTEGER" } else { "TEXT" });
+                // NULL 은 서로 다른 값으로 취급되므로 키에는 빈 값을 저장
+                if T::KEYS.contains(name) {
+                    column.push_str(if integer {
+                        " NOT NULL DEFAULT 0"
+                    } else {
+                        " NOT NULL DEFAULT ''"
+                    });
+                }
+                column
+            })
+            .collect();
+        columns.push(format!("PRIMARY KEY ({})", T::KEYS.join(", ")));
+
+        let sql = format!(
+            "CREATE TABLE IF NOT EXISTS {} (\n    {}\n)",
+            T::NAME,
+            columns.join(",\n    ")
+        );
+        self.conn.execute_batch(&sql)?;
+        Ok(())
+    }
+
+    /// 항목을 하나의 트랜잭션으로 반영
+    ///
+    /// 수정일자만 비교하지 않고 저장된 행 전체와 비교한다.
+    /// NEIS 는 수업내용, 요리명 같은 값을 고치고도 수정일자를 그대로 두는 경우가 있어,
+    /// 수정일자만 보면 바뀐 행을 `unchanged` 로 건너뛰게 된다.
+    pub fn upsert<T: Table>(&mut self, items: &[T]) -> Result<SyncReport, Error> {
+        let names: Vec<&str> = T::FIELDS.iter().map(|(name, _)| *name).collect();
+        let key_indexes: Vec<usize> = T::KEYS
+            .iter()
+            .filter_map(|key| names.iter().position(|name| name == key))
+            .collect();
+
+        let select = format!(
+            "SELECT {} FROM {} WHERE {}",
+            names.join(", "),
+            T::NAME,
+            T::KEYS
+                .iter()
+                .map(|key| format!("{} = ?", key))
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        );
+        let insert = format!(
+            "INSERT OR REPLACE INTO {} ({}) VALUES ({})",
+            T::NAME,
+            names.join(", "),
+            vec!["?"; names.len()].join(", ")
+        );
+
+        let mut report = SyncReport::default();
+        let tx = self.conn.transaction()?;
+        {
+            let mut select = tx.prepare_cached(&select)?;
+            let mut insert = tx.prepare_cached(&insert)?;
+
+            for item in items {
+                let row = row_values(item)?;
+                let key = key_indexes.iter().map(|i| &row[*i]);
+                let existing: Option<Vec<Value>> = select
+                    .query_row(params_from_iter(key), |r| {
+                        (0..names.len()).map(|i| r.get(i)).collect()
+                    })
+                    .optional()?;
+
+                match existing {
+                    None => report.inserted += 1,
+                    Some(existing) if same_row(&existing, &row) => {
+                        report.unchanged += 1;
+                        continue;
+                    }
+                    Some(_) => report.updated += 1,
+                }
+                insert.execute(params_from_iter(&row))?;
+            }
+        }
+        tx.commit()?;
+
+        Ok(report)
+    }
+
+    /// 조회 조건에 해당하는 모든 페이지를 받아 반영
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::sync::SqliteMirror;
+    /// use neis_client::{types::MealServiceParams, Error, NeisClient};
+    ///
+    /// # async fn foo() -> Result<(), Error> {
+    /// let api_key = std::env::var("NEIS_API_KEY").unwrap();
+    /// let client = NeisClient::new(&api_key);
+    ///
+    /// let mut mirror = SqliteMirror::open("neis.db")?;
+    /// mirror.create_tables()?;
+    ///
+    /// let params = MealServiceParams::new("B10", "7010959").from_ymd(2025, 3, 1);
+    /// let report = mirror.sync(&client, params).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sync<P>(&mut self, client: &NeisClient, params: P) -> Result<SyncReport, Error>
+    where
+        P: Endpoint,
+        P::Item: Table + ExtractFromResponse,
+    {
+        let items = client.fetch(params).await?;
+        self.upsert(&items)
+    }
+}
+
+/// 열 형식 변환(TEXT 열의 숫자 등)과 관계없이 같은 값인지 비교
+fn same_row(stored: &[Value], row: &[Value]) -> bool {
+    fn text(value: &Value) -> Option<String> {
+        match value {
+            Value::Null => None,
+            Value::Integer(i) => Some(i.to_string()),
+            Value::Real(f) => Some(f.to_string()),
+            Value::Text(s) => Some(s.clone()),
+            Value::Blob(b) => Some(String::from_utf8_lossy(b).into_owned()),
+        }
+    }
+
+    stored.len() == row.len() && stored.iter().zip(row).all(|(a, b)| text(a) == text(b))
+}
+
+/// `FIELDS` 순서의 열 값
+fn row_values<T: Table>(item: &T) -> Result<Vec<Value>, Error> {
+    let object = match serde_json::to_value(item)? {
+        serde_json::Value::Object(object) => object,
+        _ => return Err(Error::new_unknown("item is not a struct")),
+    };
+
+    let row = T::FIELDS
+        .iter()
+        .map(|(name, _)| match object.get(*name) {
+            Some(serde_json::Value::String(s)) => Value::Text(s.clone()),
+            Some(serde_json::Value::Number(n)) => match n.as_i64() {
+                Some(i) => Value::Integer(i),
+                None => Value::Real(n.as_f64().unwrap_or_default()),
+            },
+            Some(serde_json::Value::Bool(b)) => Value::Integer(*b as i64),
+            _ if T::KEYS.contains(name) => Value::Text(String::new()),
+            _ => Value::Null,
+        })
+        .collect();
+
+    Ok(row)
+}
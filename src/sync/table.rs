@@ -0,0 +1,162 @@
+use crate::types::*;
+use crate::watermark::Modified;
+use serde::Serialize;
+
+/// SQLite 테이블로 미러링하는 응답 항목
+///
+/// 열은 [`FieldLabels::FIELDS`] 순서를 따르며,
+/// 같은 자연키의 행은 하나만 유지한다.
+pub trait Table: Serialize + FieldLabels + Modified {
+    /// 테이블명
+    const NAME: &'static str;
+    /// 자연키 열
+    const KEYS: &'static [&'static str];
+    /// INTEGER 로 저장하는 열, 나머지는 TEXT
+    const INTEGERS: &'static [&'static str] = &[];
+}
+
+impl Table for SchoolInfoItem {
+    const NAME: &'static str = "school_info";
+    const KEYS: &'static [&'static str] = &["ATPT_OFCDC_SC_CODE", "SD_SCHUL_CODE"];
+}
+
+impl Table for ClassInfoItem {
+    const NAME: &'static str = "class_info";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "AY",
+        "GRADE",
+        "DGHT_CRSE_SC_NM",
+        "SCHUL_CRSE_SC_NM",
+        "ORD_SC_NM",
+        "DDDEP_NM",
+        "CLASS_NM",
+    ];
+    const INTEGERS: &'static [&'static str] = &["AY", "GRADE"];
+}
+
+impl Table for SchoolMajorInfoItem {
+    const NAME: &'static str = "school_major_info";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "DGHT_CRSE_SC_NM",
+        "ORD_SC_NM",
+        "DDDEP_NM",
+    ];
+}
+
+impl Table for SchoolAflcoInfoItem {
+    const NAME: &'static str = "school_aflco_info";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "DGHT_CRSE_SC_NM",
+        "ORD_SC_NM",
+    ];
+}
+
+impl Table for SchoolScheduleItem {
+    const NAME: &'static str = "school_schedule";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "DGHT_CRSE_SC_NM",
+        "SCHUL_CRSE_SC_NM",
+        "AA_YMD",
+        "EVENT_NM",
+    ];
+    const INTEGERS: &'static [&'static str] = &["AY"];
+}
+
+impl Table for ElsTimetableItem {
+    const NAME: &'static str = "els_timetable";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "ALL_TI_YMD",
+        "GRADE",
+        "CLASS_NM",
+        "PERIO",
+    ];
+    const INTEGERS: &'static [&'static str] = &["AY", "SEM", "GRADE", "PERIO"];
+}
+
+impl Table for MisTimetableItem {
+    const NAME: &'static str = "mis_timetable";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "ALL_TI_YMD",
+        "DGHT_CRSE_SC_NM",
+        "GRADE",
+        "CLASS_NM",
+        "PERIO",
+    ];
+    const INTEGERS: &'static [&'static str] = &["AY", "SEM", "GRADE", "PERIO"];
+}
+
+impl Table for HisTimetableItem {
+    const NAME: &'static str = "his_timetable";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "ALL_TI_YMD",
+        "DGHT_CRSE_SC_NM",
+        "ORD_SC_NM",
+        "DDDEP_NM",
+        "GRADE",
+        "CLASS_NM",
+        "PERIO",
+    ];
+    const INTEGERS: &'static [&'static str] = &["AY", "SEM", "GRADE", "PERIO"];
+}
+
+impl Table for SpsTimetableItem {
+    const NAME: &'static str = "sps_timetable";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "ALL_TI_YMD",
+        "SCHUL_CRSE_SC_NM",
+        "GRADE",
+        "CLASS_NM",
+        "PERIO",
+    ];
+    const INTEGERS: &'static [&'static str] = &["AY", "SEM", "GRADE", "PERIO"];
+}
+
+impl Table for ClassRoomInfoItem {
+    const NAME: &'static str = "class_room_info";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "AY",
+        "SEM",
+        "GRADE",
+        "SCHUL_CRSE_SC_NM",
+        "DGHT_CRSE_SC_NM",
+        "ORD_SC_NM",
+        "DDDEP_NM",
+        "CLRM_NM",
+    ];
+    const INTEGERS: &'static [&'static str] = &["AY", "GRADE", "SEM"];
+}
+
+impl Table for AcademyInfoItem {
+    const NAME: &'static str = "academy_info";
+    const KEYS: &'static [&'static str] = &["ATPT_OFCDC_SC_CODE", "ACA_ASNUM"];
+    const INTEGERS: &'static [&'static str] = &["TOFOR_SMTOT", "DTM_RCPTN_ABLTY_NMPR_SMTOT"];
+}
+
+impl Table for MealServiceItem {
+    const NAME: &'static str = "meal_service";
+    const KEYS: &'static [&'static str] = &[
+        "ATPT_OFCDC_SC_CODE",
+        "SD_SCHUL_CODE",
+        "MLSV_YMD",
+        "MMEAL_SC_CODE",
+    ];
+    const INTEGERS: &'static [&'static str] = &["MLSV_FGR"];
+}
@@ -0,0 +1,27 @@
+use super::SqliteMirror;
+use crate::Error;
+use crate::watermark::WatermarkStore;
+use rusqlite::OptionalExtension;
+
+/// 미러 DB 의 `sync_watermark` 테이블
+impl WatermarkStore for SqliteMirror {
+    fn get(&self, endpoint: &str, query: &str) -> Result<Option<String>, Error> {
+        let load_dtm = self
+            .conn
+            .query_row(
+                "SELECT LOAD_DTM FROM sync_watermark WHERE ENDPOINT = ?1 AND QUERY = ?2",
+                [endpoint, query],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(load_dtm)
+    }
+
+    fn set(&mut self, endpoint: &str, query: &str, load_dtm: &str) -> Result<(), Error> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO sync_watermark (ENDPOINT, QUERY, LOAD_DTM) VALUES (?1, ?2, ?3)",
+            [endpoint, query, load_dtm],
+        )?;
+        Ok(())
+    }
+}
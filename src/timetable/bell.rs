@@ -0,0 +1,149 @@
+use super::TimetableKind;
+use crate::types::SchoolKind;
+use chrono::{Duration, NaiveTime};
+use std::collections::BTreeMap;
+
+/// 한 교시의 시작, 종료 시각
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PeriodTime {
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl PeriodTime {
+    pub fn new(start: NaiveTime, end: NaiveTime) -> Self {
+        Self { start, end }
+    }
+
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        self.start <= time && time < self.end
+    }
+}
+
+impl std::fmt::Display for PeriodTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}–{}",
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M")
+        )
+    }
+}
+
+/// 교시별 시작, 종료 시각
+///
+/// 학교마다 일과 시간이 다르므로 기본값을 바탕으로 `set_period` 로 고쳐 쓴다.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveTime;
+/// use neis_client::timetable::BellSchedule;
+///
+/// let bell = BellSchedule::middle();
+/// assert_eq!(bell.period(3).unwrap().to_string(), "10:50–11:35");
+///
+/// let now = NaiveTime::from_hms_opt(10, 45, 0).unwrap();
+/// assert_eq!(bell.current_period(now), None); // 쉬는 시간
+/// assert_eq!(bell.next_period(now), Some(3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BellSchedule {
+    periods: BTreeMap<u8, PeriodTime>,
+}
+
+impl BellSchedule {
+    /// 교시가 없는 빈 일과표
+    pub fn new() -> Self {
+        Self {
+            periods: BTreeMap::new(),
+        }
+    }
+
+    /// 수업 시간과 쉬는 시간이 일정한 일과표
+    ///
+    /// `lunch_after` 교시가 끝난 뒤 `lunch_minutes` 동안 점심시간을 둔다.
+    pub fn uniform(
+        first_start: NaiveTime,
+        lesson_minutes: i64,
+        break_minutes: i64,
+        lunch_after: u8,
+        lunch_minutes: i64,
+        periods: u8,
+    ) -> Self {
+        let mut schedule = Self::new();
+        let mut start = first_start;
+        for perio in 1..=periods {
+            let end = start + Duration::minutes(lesson_minutes);
+            schedule.set_period(perio, PeriodTime::new(start, end));
+            start = if perio == lunch_after {
+                end + Duration::minutes(lunch_minutes)
+            } else {
+                end + Duration::minutes(break_minutes)
+            };
+        }
+        schedule
+    }
+
+    /// 초등학교: 09:00 시작, 40분 수업, 6교시
+    pub fn elementary() -> Self {
+        Self::uniform(hm(9, 0), 40, 10, 4, 50, 6)
+    }
+
+    /// 중학교: 09:00 시작, 45분 수업, 7교시
+    pub fn middle() -> Self {
+        Self::uniform(hm(9, 0), 45, 10, 4, 50, 7)
+    }
+
+    /// 고등학교: 08:40 시작, 50분 수업, 7교시
+    pub fn high() -> Self {
+        Self::uniform(hm(8, 40), 50, 10, 4, 50, 7)
+    }
+
+    /// 학교종류에 맞는 기본 일과표
+    pub fn for_school_kind(school_kind: &SchoolKind) -> Option<Self> {
+        match TimetableKind::for_school_kind(school_kind)? {
+            TimetableKind::Els => Some(Self::elementary()),
+            TimetableKind::Mis => Some(Self::middle()),
+            TimetableKind::His => Some(Self::high()),
+            TimetableKind::Sps => None, // 학교마다 편차가 큼
+        }
+    }
+
+    pub fn set_period(&mut self, perio: u8, time: PeriodTime) {
+        self.periods.insert(perio, time);
+    }
+
+    pub fn period(&self, perio: u8) -> Option<PeriodTime> {
+        self.periods.get(&perio).copied()
+    }
+
+    pub fn periods(&self) -> impl Iterator<Item = (u8, PeriodTime)> + '_ {
+        self.periods.iter().map(|(perio, time)| (*perio, *time))
+    }
+
+    /// 지금 진행 중인 교시
+    pub fn current_period(&self, now: NaiveTime) -> Option<u8> {
+        self.periods()
+            .find(|(_, time)| time.contains(now))
+            .map(|(perio, _)| perio)
+    }
+
+    /// 다음에 시작할 교시
+    pub fn next_period(&self, now: NaiveTime) -> Option<u8> {
+        self.periods()
+            .find(|(_, time)| now < time.start)
+            .map(|(perio, _)| perio)
+    }
+}
+
+impl Default for BellSchedule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn hm(hour: u32, min: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, min, 0).unwrap()
+}
@@ -0,0 +1,166 @@
+use super::TimetableEntry;
+use chrono::NaiveDate;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// 두 번 조회한 시간표 사이의 (일자, 교시, 학년, 학급) 별 변경 내용
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum TimetableChange {
+    /// 새로 생긴 수업
+    Added {
+        date: NaiveDate,
+        perio: u8,
+        grade: u8,
+        class_nm: String,
+        after: String,
+    },
+    /// 없어진 수업
+    Removed {
+        date: NaiveDate,
+        perio: u8,
+        grade: u8,
+        class_nm: String,
+        before: String,
+    },
+    /// 수업내용이 바뀐 수업
+    Changed {
+        date: NaiveDate,
+        perio: u8,
+        grade: u8,
+        class_nm: String,
+        before: String,
+        after: String,
+    },
+}
+
+impl TimetableChange {
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            TimetableChange::Added { date, .. }
+            | TimetableChange::Removed { date, .. }
+            | TimetableChange::Changed { date, .. } => *date,
+        }
+    }
+
+    pub fn perio(&self) -> u8 {
+        match self {
+            TimetableChange::Added { perio, .. }
+            | TimetableChange::Removed { perio, .. }
+            | TimetableChange::Changed { perio, .. } => *perio,
+        }
+    }
+
+    pub fn grade(&self) -> u8 {
+        match self {
+            TimetableChange::Added { grade, .. }
+            | TimetableChange::Removed { grade, .. }
+            | TimetableChange::Changed { grade, .. } => *grade,
+        }
+    }
+
+    pub fn class_nm(&self) -> &str {
+        match self {
+            TimetableChange::Added { class_nm, .. }
+            | TimetableChange::Removed { class_nm, .. }
+            | TimetableChange::Changed { class_nm, .. } => class_nm,
+        }
+    }
+}
+
+// (일자, 교시, 학년, 학급)
+type SlotKey = (NaiveDate, u8, u8, String);
+
+/// 같은 기간의 시간표를 비교
+///
+/// 여러 학년, 학급을 함께 조회한 시간표도 학급별로 나눠 비교한다.
+/// 한 칸에 여러 수업이 있으면 수업내용을 정렬해 ` / ` 로 이어 비교한다.
+/// 결과는 일자, 교시, 학년, 학급 순으로 정렬된다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::timetable::{TimetableChange, diff};
+/// use neis_client::types::MisTimetableItem;
+///
+/// fn print_changes(before: &[MisTimetableItem], after: &[MisTimetableItem]) {
+///     for change in diff(before, after) {
+///         match change {
+///             TimetableChange::Changed { before, after, .. } => {
+///                 println!("{} → {}", before, after)
+///             }
+///             TimetableChange::Added { after, .. } => println!("추가: {}", after),
+///             TimetableChange::Removed { before, .. } => println!("삭제: {}", before),
+///         }
+///     }
+/// }
+/// ```
+pub fn diff<A, B>(before: &[A], after: &[B]) -> Vec<TimetableChange>
+where
+    A: TimetableEntry,
+    B: TimetableEntry,
+{
+    let before = slots(before);
+    let mut after = slots(after);
+
+    let mut changes = Vec::new();
+    for (key, before) in before {
+        let after = after.remove(&key);
+        let (date, perio, grade, class_nm) = key;
+        match after {
+            Some(after) if after == before => {}
+            Some(after) => changes.push(TimetableChange::Changed {
+                date,
+                perio,
+                grade,
+                class_nm,
+                before,
+                after,
+            }),
+            None => changes.push(TimetableChange::Removed {
+                date,
+                perio,
+                grade,
+                class_nm,
+                before,
+            }),
+        }
+    }
+    for ((date, perio, grade, class_nm), after) in after {
+        changes.push(TimetableChange::Added {
+            date,
+            perio,
+            grade,
+            class_nm,
+            after,
+        });
+    }
+
+    changes.sort_by(|a, b| {
+        (a.date(), a.perio(), a.grade(), a.class_nm()).cmp(&(
+            b.date(),
+            b.perio(),
+            b.grade(),
+            b.class_nm(),
+        ))
+    });
+    changes
+}
+
+fn slots<E: TimetableEntry>(entries: &[E]) -> BTreeMap<SlotKey, String> {
+    let mut slots: BTreeMap<SlotKey, BTreeSet<&str>> = BTreeMap::new();
+    for entry in entries {
+        let Some(date) = entry.date() else {
+            tracing::warn!(ALL_TI_YMD = entry.ymd(), "invalid timetable date");
+            continue;
+        };
+        let class_nm = entry.class_nm().unwrap_or_default().trim().to_owned();
+        slots
+            .entry((date, entry.perio(), entry.grade(), class_nm))
+            .or_default()
+            .insert(entry.content().unwrap_or_default().trim());
+    }
+
+    slots
+        .into_iter()
+        .map(|(key, contents)| (key, contents.into_iter().collect::<Vec<_>>().join(" / ")))
+        .collect()
+}
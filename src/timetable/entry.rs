@@ -0,0 +1,222 @@
+use super::{BellSchedule, Lesson};
+use crate::types::{
+    ElsTimetableItem, HisTimetableItem, MisTimetableItem, SpsTimetableItem, parse_ymd,
+};
+use chrono::{NaiveDate, NaiveDateTime};
+
+/// 초등학교, 중학교, 고등학교, 특수학교 시간표의 공통 항목
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::timetable::TimetableEntry;
+///
+/// fn subjects_of_day<E: TimetableEntry>(entries: &[E], ymd: &str) -> Vec<String> {
+///     let mut entries: Vec<&E> = entries.iter().filter(|e| e.ymd() == ymd).collect();
+///     entries.sort_by_key(|e| e.perio());
+///     entries
+///         .into_iter()
+///         .filter_map(|e| e.lesson())
+///         .map(|lesson| lesson.subject)
+///         .collect()
+/// }
+/// ```
+pub trait TimetableEntry {
+    /// 시도교육청코드
+    fn office_code(&self) -> &str;
+    /// 행정표준코드
+    fn school_code(&self) -> &str;
+    /// 학교명
+    fn school_name(&self) -> &str;
+    /// 학년도
+    fn ay(&self) -> i32;
+    /// 학기
+    fn sem(&self) -> u8;
+    /// 시간표일자
+    fn ymd(&self) -> &str;
+    /// 학년
+    fn grade(&self) -> u8;
+    /// 학급명
+    fn class_nm(&self) -> Option<&str>;
+    /// 교시
+    fn perio(&self) -> u8;
+    /// 수업내용
+    fn content(&self) -> Option<&str>;
+    /// 수정일자
+    fn load_dtm(&self) -> &str;
+
+    /// 강의실명
+    /// 고등학교, 특수학교 시간표에만 있음
+    fn classroom(&self) -> Option<&str> {
+        None
+    }
+
+    /// 시간표일자
+    fn date(&self) -> Option<NaiveDate> {
+        parse_ymd(self.ymd())
+    }
+
+    /// 수업내용을 과목명과 태그로 나눔
+    fn lesson(&self) -> Option<Lesson> {
+        self.content()
+            .filter(|s| !s.trim().is_empty())
+            .map(Lesson::parse)
+    }
+
+    /// 일과표에 따른 수업 시작, 종료 일시
+    fn times(&self, bell: &BellSchedule) -> Option<(NaiveDateTime, NaiveDateTime)> {
+        let date = self.date()?;
+        let time = bell.period(self.perio())?;
+        Some((date.and_time(time.start), date.and_time(time.end)))
+    }
+}
+
+impl TimetableEntry for ElsTimetableItem {
+    fn office_code(&self) -> &str {
+        &self.ATPT_OFCDC_SC_CODE
+    }
+    fn school_code(&self) -> &str {
+        &self.SD_SCHUL_CODE
+    }
+    fn school_name(&self) -> &str {
+        &self.SCHUL_NM
+    }
+    fn ay(&self) -> i32 {
+        self.AY
+    }
+    fn sem(&self) -> u8 {
+        self.SEM
+    }
+    fn ymd(&self) -> &str {
+        &self.ALL_TI_YMD
+    }
+    fn grade(&self) -> u8 {
+        self.GRADE
+    }
+    fn class_nm(&self) -> Option<&str> {
+        self.CLASS_NM.as_deref()
+    }
+    fn perio(&self) -> u8 {
+        self.PERIO
+    }
+    fn content(&self) -> Option<&str> {
+        self.ITRT_CNTNT.as_deref()
+    }
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl TimetableEntry for MisTimetableItem {
+    fn office_code(&self) -> &str {
+        &self.ATPT_OFCDC_SC_CODE
+    }
+    fn school_code(&self) -> &str {
+        &self.SD_SCHUL_CODE
+    }
+    fn school_name(&self) -> &str {
+        &self.SCHUL_NM
+    }
+    fn ay(&self) -> i32 {
+        self.AY
+    }
+    fn sem(&self) -> u8 {
+        self.SEM
+    }
+    fn ymd(&self) -> &str {
+        &self.ALL_TI_YMD
+    }
+    fn grade(&self) -> u8 {
+        self.GRADE
+    }
+    fn class_nm(&self) -> Option<&str> {
+        self.CLASS_NM.as_deref()
+    }
+    fn perio(&self) -> u8 {
+        self.PERIO
+    }
+    fn content(&self) -> Option<&str> {
+        self.ITRT_CNTNT.as_deref()
+    }
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl TimetableEntry for HisTimetableItem {
+    fn office_code(&self) -> &str {
+        &self.ATPT_OFCDC_SC_CODE
+    }
+    fn school_code(&self) -> &str {
+        &self.SD_SCHUL_CODE
+    }
+    fn school_name(&self) -> &str {
+        &self.SCHUL_NM
+    }
+    fn ay(&self) -> i32 {
+        self.AY
+    }
+    fn sem(&self) -> u8 {
+        self.SEM
+    }
+    fn ymd(&self) -> &str {
+        &self.ALL_TI_YMD
+    }
+    fn grade(&self) -> u8 {
+        self.GRADE
+    }
+    fn class_nm(&self) -> Option<&str> {
+        self.CLASS_NM.as_deref()
+    }
+    fn perio(&self) -> u8 {
+        self.PERIO
+    }
+    fn content(&self) -> Option<&str> {
+        self.ITRT_CNTNT.as_deref()
+    }
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+    fn classroom(&self) -> Option<&str> {
+        self.CLRM_NM.as_deref()
+    }
+}
+
+impl TimetableEntry for SpsTimetableItem {
+    fn office_code(&self) -> &str {
+        &self.ATPT_OFCDC_SC_CODE
+    }
+    fn school_code(&self) -> &str {
+        &self.SD_SCHUL_CODE
+    }
+    fn school_name(&self) -> &str {
+        &self.SCHUL_NM
+    }
+    fn ay(&self) -> i32 {
+        self.AY
+    }
+    fn sem(&self) -> u8 {
+        self.SEM
+    }
+    fn ymd(&self) -> &str {
+        &self.ALL_TI_YMD
+    }
+    fn grade(&self) -> u8 {
+        self.GRADE
+    }
+    fn class_nm(&self) -> Option<&str> {
+        self.CLASS_NM.as_deref()
+    }
+    fn perio(&self) -> u8 {
+        self.PERIO
+    }
+    fn content(&self) -> Option<&str> {
+        self.ITRT_CNTNT.as_deref()
+    }
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+    fn classroom(&self) -> Option<&str> {
+        self.CLRM_NM.as_deref()
+    }
+}
@@ -0,0 +1,197 @@
+use super::{Lesson, TimetableEntry};
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use std::collections::BTreeMap;
+
+const WEEKDAYS: [Weekday; 5] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+];
+
+/// 한 학급의 일주일(월~금) × 교시 시간표
+///
+/// 같은 칸의 중복 행은 하나로 합치고, 서로 다른 수업은 모두 유지한다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::timetable::TimetableGrid;
+/// use neis_client::types::ElsTimetableItem;
+///
+/// fn print_class(items: &[ElsTimetableItem]) {
+///     if let Some(grid) = TimetableGrid::from_entries(items, 6, "1") {
+///         print!("{}", grid.to_markdown());
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimetableGrid {
+    week_start: NaiveDate,
+    periods: u8,
+    cells: BTreeMap<(usize, u8), Vec<Lesson>>,
+}
+
+impl TimetableGrid {
+    /// 해당 학년, 학급의 가장 이른 시간표일자가 속한 주의 시간표
+    /// 해당 학급의 시간표가 없으면 `None`
+    pub fn from_entries<E: TimetableEntry>(
+        entries: &[E],
+        grade: u8,
+        class_nm: &str,
+    ) -> Option<Self> {
+        let first = entries
+            .iter()
+            .filter(|e| is_class(*e, grade, class_nm))
+            .filter_map(|e| e.date())
+            .min()?;
+
+        Some(Self::for_week(entries, grade, class_nm, first))
+    }
+
+    /// `date` 가 속한 주의 시간표
+    pub fn for_week<E: TimetableEntry>(
+        entries: &[E],
+        grade: u8,
+        class_nm: &str,
+        date: NaiveDate,
+    ) -> Self {
+        let week_start = date.week(Weekday::Mon).first_day();
+        let mut grid = Self {
+            week_start,
+            periods: 0,
+            cells: BTreeMap::new(),
+        };
+
+        for entry in entries.iter().filter(|e| is_class(*e, grade, class_nm)) {
+            let Some(date) = entry.date() else {
+                continue;
+            };
+            let Some(day) = WEEKDAYS.iter().position(|d| *d == date.weekday()) else {
+                continue; // 주말
+            };
+            if date.week(Weekday::Mon).first_day() != week_start {
+                continue;
+            }
+
+            grid.periods = grid.periods.max(entry.perio());
+            let cell = grid.cells.entry((day, entry.perio())).or_default();
+            if let Some(lesson) = entry.lesson()
+                && !cell.contains(&lesson)
+            {
+                cell.push(lesson);
+            }
+        }
+
+        grid
+    }
+
+    /// 월요일 일자
+    pub fn week_start(&self) -> NaiveDate {
+        self.week_start
+    }
+
+    /// 월요일부터 금요일까지의 일자
+    pub fn dates(&self) -> [NaiveDate; 5] {
+        std::array::from_fn(|i| self.week_start + Days::new(i as u64))
+    }
+
+    /// 가장 늦은 교시
+    pub fn periods(&self) -> u8 {
+        self.periods
+    }
+
+    /// 해당 요일, 교시의 수업
+    /// 주말이나 수업이 없는 칸은 빈 슬라이스
+    pub fn cell(&self, weekday: Weekday, perio: u8) -> &[Lesson] {
+        WEEKDAYS
+            .iter()
+            .position(|d| *d == weekday)
+            .and_then(|day| self.cells.get(&(day, perio)))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// 공백으로 열을 맞춘 텍스트 표
+    pub fn to_text(&self) -> String {
+        let rows = self.rows();
+        let widths: Vec<usize> = (0..rows[0].len())
+            .map(|i| {
+                rows.iter()
+                    .map(|row| display_width(&row[i]))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let mut text = String::new();
+        for row in &rows {
+            let line: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| {
+                    let padding = width - display_width(cell);
+                    format!("{}{}", cell, " ".repeat(padding))
+                })
+                .collect();
+            text.push_str(line.join("  ").trim_end());
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Markdown 표
+    pub fn to_markdown(&self) -> String {
+        let rows = self.rows();
+        let mut text = String::new();
+        for (i, row) in rows.iter().enumerate() {
+            let cells: Vec<String> = row.iter().map(|cell| cell.replace('|', "\\|")).collect();
+            text.push_str(&format!("| {} |\n", cells.join(" | ")));
+            if i == 0 {
+                text.push_str(&format!("|{}\n", "---|".repeat(row.len())));
+            }
+        }
+        text
+    }
+
+    fn rows(&self) -> Vec<Vec<String>> {
+        let mut header = vec![String::from("교시")];
+        for (weekday, date) in ["월", "화", "수", "목", "금"].iter().zip(self.dates()) {
+            header.push(format!("{} {}", weekday, date.format("%m/%d")));
+        }
+
+        let mut rows = vec![header];
+        for perio in 1..=self.periods {
+            let mut row = vec![perio.to_string()];
+            for weekday in WEEKDAYS {
+                let lessons: Vec<String> = self
+                    .cell(weekday, perio)
+                    .iter()
+                    .map(Lesson::to_string)
+                    .collect();
+                row.push(lessons.join(" / "));
+            }
+            rows.push(row);
+        }
+        rows
+    }
+}
+
+fn is_class<E: TimetableEntry>(entry: &E, grade: u8, class_nm: &str) -> bool {
+    entry.grade() == grade && entry.class_nm().map(str::trim) == Some(class_nm)
+}
+
+// 한글 등 전각 문자는 두 칸으로 계산
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60 => 2,
+            _ => 1,
+        })
+        .sum()
+}
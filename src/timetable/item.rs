@@ -0,0 +1,127 @@
+use super::TimetableEntry;
+use crate::types::{
+    ElsTimetableItem, HisTimetableItem, MisTimetableItem, SchoolKind, SpsTimetableItem,
+};
+
+/// 학교 종류에 따른 시간표 API
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum TimetableKind {
+    /// 초등학교시간표
+    Els,
+    /// 중학교시간표
+    Mis,
+    /// 고등학교시간표
+    His,
+    /// 특수학교시간표
+    Sps,
+}
+
+impl TimetableKind {
+    /// 학교종류명에 맞는 시간표 API
+    /// 각종학교 등 시간표 API 가 없는 경우 `None`
+    pub fn for_school_kind(school_kind: &SchoolKind) -> Option<Self> {
+        match school_kind {
+            SchoolKind::Elementary => Some(TimetableKind::Els),
+            SchoolKind::Middle | SchoolKind::BroadcastMiddle | SchoolKind::CivicHigh => {
+                Some(TimetableKind::Mis)
+            }
+            SchoolKind::High | SchoolKind::BroadcastHigh | SchoolKind::TechnicalHigh => {
+                Some(TimetableKind::His)
+            }
+            SchoolKind::Special => Some(TimetableKind::Sps),
+            _ => None,
+        }
+    }
+}
+
+/// 학교 종류와 상관없는 시간표 항목
+#[derive(Debug, Clone, Hash)]
+pub enum TimetableItem {
+    Els(ElsTimetableItem),
+    Mis(MisTimetableItem),
+    His(HisTimetableItem),
+    Sps(SpsTimetableItem),
+}
+
+impl TimetableItem {
+    pub fn kind(&self) -> TimetableKind {
+        match self {
+            TimetableItem::Els(_) => TimetableKind::Els,
+            TimetableItem::Mis(_) => TimetableKind::Mis,
+            TimetableItem::His(_) => TimetableKind::His,
+            TimetableItem::Sps(_) => TimetableKind::Sps,
+        }
+    }
+
+    fn entry(&self) -> &dyn TimetableEntry {
+        match self {
+            TimetableItem::Els(item) => item,
+            TimetableItem::Mis(item) => item,
+            TimetableItem::His(item) => item,
+            TimetableItem::Sps(item) => item,
+        }
+    }
+}
+
+impl TimetableEntry for TimetableItem {
+    fn office_code(&self) -> &str {
+        self.entry().office_code()
+    }
+    fn school_code(&self) -> &str {
+        self.entry().school_code()
+    }
+    fn school_name(&self) -> &str {
+        self.entry().school_name()
+    }
+    fn ay(&self) -> i32 {
+        self.entry().ay()
+    }
+    fn sem(&self) -> u8 {
+        self.entry().sem()
+    }
+    fn ymd(&self) -> &str {
+        self.entry().ymd()
+    }
+    fn grade(&self) -> u8 {
+        self.entry().grade()
+    }
+    fn class_nm(&self) -> Option<&str> {
+        self.entry().class_nm()
+    }
+    fn perio(&self) -> u8 {
+        self.entry().perio()
+    }
+    fn content(&self) -> Option<&str> {
+        self.entry().content()
+    }
+    fn load_dtm(&self) -> &str {
+        self.entry().load_dtm()
+    }
+    fn classroom(&self) -> Option<&str> {
+        self.entry().classroom()
+    }
+}
+
+impl From<ElsTimetableItem> for TimetableItem {
+    fn from(item: ElsTimetableItem) -> Self {
+        TimetableItem::Els(item)
+    }
+}
+
+impl From<MisTimetableItem> for TimetableItem {
+    fn from(item: MisTimetableItem) -> Self {
+        TimetableItem::Mis(item)
+    }
+}
+
+impl From<HisTimetableItem> for TimetableItem {
+    fn from(item: HisTimetableItem) -> Self {
+        TimetableItem::His(item)
+    }
+}
+
+impl From<SpsTimetableItem> for TimetableItem {
+    fn from(item: SpsTimetableItem) -> Self {
+        TimetableItem::Sps(item)
+    }
+}
@@ -0,0 +1,77 @@
+/// 수업내용(ITRT_CNTNT)을 과목명과 태그로 나눈 결과
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::timetable::Lesson;
+///
+/// let lesson = Lesson::parse("[보강]음악 감상과 비평");
+/// assert_eq!(lesson.subject, "음악 감상과 비평");
+/// assert_eq!(lesson.tags, vec!["보강"]);
+/// assert!(lesson.is_substitute());
+///
+/// let lesson = Lesson::parse("[기]진로와 직업");
+/// assert_eq!(lesson.subject, "진로와 직업");
+/// assert!(!lesson.is_substitute());
+/// ```
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Lesson {
+    /// 태그를 제외한 과목명
+    /// Example: 음악 감상과 비평
+    pub subject: String,
+
+    /// 대괄호로 표시된 태그
+    /// Example: 보강
+    pub tags: Vec<String>,
+}
+
+impl Lesson {
+    pub fn parse(content: &str) -> Self {
+        let mut subject = String::new();
+        let mut tags = Vec::new();
+
+        let mut rest = content;
+        while let Some(open) = rest.find('[') {
+            let Some(close) = rest[open..].find(']') else {
+                break; // 닫히지 않은 괄호는 과목명으로 취급
+            };
+            subject.push_str(&rest[..open]);
+            let tag = rest[open + 1..open + close].trim();
+            if !tag.is_empty() {
+                tags.push(tag.to_owned());
+            }
+            rest = &rest[open + close + 1..];
+        }
+        subject.push_str(rest);
+
+        Self {
+            subject: subject.split_whitespace().collect::<Vec<_>>().join(" "),
+            tags,
+        }
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// 보강, 대강 등 원래 수업을 대신하는 수업인지 여부
+    pub fn is_substitute(&self) -> bool {
+        self.tags
+            .iter()
+            .any(|t| matches!(t.as_str(), "보강" | "대강" | "대체"))
+    }
+
+    /// 선택 과목인지 여부
+    pub fn is_elective(&self) -> bool {
+        self.tags.iter().any(|t| t.starts_with("선택") || t == "선")
+    }
+}
+
+impl std::fmt::Display for Lesson {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for tag in &self.tags {
+            write!(f, "[{}]", tag)?;
+        }
+        f.write_str(&self.subject)
+    }
+}
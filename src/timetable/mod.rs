@@ -0,0 +1,15 @@
+mod bell;
+mod diff;
+mod entry;
+mod grid;
+mod item;
+mod lesson;
+mod query;
+
+pub use bell::{BellSchedule, PeriodTime};
+pub use diff::{TimetableChange, diff};
+pub use entry::TimetableEntry;
+pub use grid::TimetableGrid;
+pub use item::{TimetableItem, TimetableKind};
+pub use lesson::Lesson;
+pub use query::TimetableQuery;
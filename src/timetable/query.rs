@@ -0,0 +1,86 @@
+#![allow(non_snake_case)]
+use crate::types::{
+    ElsTimetableParams, HisTimetableParams, MisTimetableParams, SpsTimetableParams,
+};
+
+/// 학교 종류와 상관없이 사용하는 시간표 조회 조건
+#[derive(Debug, Clone, Default)]
+pub struct TimetableQuery {
+    /// 학년도
+    pub AY: Option<String>,
+    /// 학기
+    pub SEM: Option<String>,
+    /// 시간표일자
+    pub ALL_TI_YMD: Option<String>,
+    /// 학년
+    pub GRADE: Option<String>,
+    /// 학급명
+    pub CLASS_NM: Option<String>,
+    /// 시간표시작일자
+    pub TI_FROM_YMD: Option<String>,
+    /// 시간표종료일자
+    pub TI_TO_YMD: Option<String>,
+}
+
+impl TimetableQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ay(mut self, year: i32) -> Self {
+        self.AY = Some(year.to_string());
+        self
+    }
+    pub fn sem(mut self, sem: u8) -> Self {
+        self.SEM = Some(sem.to_string());
+        self
+    }
+    pub fn ymd(mut self, year: i32, month: u8, day: u8) -> Self {
+        self.ALL_TI_YMD = Some(format!("{:04}{:02}{:02}", year, month, day));
+        self
+    }
+    pub fn grade(mut self, grade: u8) -> Self {
+        self.GRADE = Some(grade.to_string());
+        self
+    }
+    pub fn class_nm(mut self, class_nm: &str) -> Self {
+        self.CLASS_NM = Some(class_nm.to_owned());
+        self
+    }
+    pub fn from_ymd(mut self, year: i32, month: u8, day: u8) -> Self {
+        self.TI_FROM_YMD = Some(format!("{:04}{:02}{:02}", year, month, day));
+        self
+    }
+    pub fn to_ymd(mut self, year: i32, month: u8, day: u8) -> Self {
+        self.TI_TO_YMD = Some(format!("{:04}{:02}{:02}", year, month, day));
+        self
+    }
+}
+
+/// 학교 종류별 조회 조건으로 옮기는 메서드, 네 조건의 필드 이름이 같다.
+macro_rules! params_from_query {
+    ($($name:ident => $params:ident),* $(,)?) => {
+        impl TimetableQuery {
+            $(
+                pub(crate) fn $name(&self, office: &str, school_code: &str) -> $params {
+                    let mut params = $params::new(office, school_code);
+                    params.AY = self.AY.clone();
+                    params.SEM = self.SEM.clone();
+                    params.ALL_TI_YMD = self.ALL_TI_YMD.clone();
+                    params.GRADE = self.GRADE.clone();
+                    params.CLASS_NM = self.CLASS_NM.clone();
+                    params.TI_FROM_YMD = self.TI_FROM_YMD.clone();
+                    params.TI_TO_YMD = self.TI_TO_YMD.clone();
+                    params
+                }
+            )*
+        }
+    };
+}
+
+params_from_query! {
+    els_params => ElsTimetableParams,
+    mis_params => MisTimetableParams,
+    his_params => HisTimetableParams,
+    sps_params => SpsTimetableParams,
+}
@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use super::{ToQueryString, YesOrNo};
+use super::{Endpoint, FieldLabels, ToQueryString, YesOrNo};
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -63,7 +63,12 @@ impl ToQueryString for AcademyInfoParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for AcademyInfoParams {
+    const RESOURCE: &'static str = "acaInsTiInfo";
+    type Item = AcademyInfoItem;
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct AcademyInfoItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -163,3 +168,33 @@ pub struct AcademyInfoItem {
     /// Example: 20231018
     pub LOAD_DTM: String,
 }
+
+impl FieldLabels for AcademyInfoItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("ADMST_ZONE_NM", "행정구역명"),
+        ("ACA_INSTI_SC_NM", "학원교습소명"),
+        ("ACA_ASNUM", "학원지정번호"),
+        ("ACA_NM", "학원명"),
+        ("ESTBL_YMD", "개설일자"),
+        ("REG_YMD", "등록일자"),
+        ("REG_STTUS_NM", "등록상태명"),
+        ("CAA_BEGIN_YMD", "휴원시작일자"),
+        ("CAA_END_YMD", "휴원종료일자"),
+        ("TOFOR_SMTOT", "정원합계"),
+        ("DTM_RCPTN_ABLTY_NMPR_SMTOT", "일시수용능력인원합계"),
+        ("REALM_SC_NM", "분야명"),
+        ("LE_ORD_NM", "교습계열명"),
+        ("LE_CRSE_LIST_NM", "교습과정목록명"),
+        ("LE_CRSE_NM", "교습과정명"),
+        ("PSNBY_THCC_CNTNT", "인당수강료"),
+        ("THCC_OTHBC_YN", "수강료공개여부"),
+        ("BRHS_ACA_YN", "기숙사학원여부"),
+        ("FA_RDNMA", "도로명주소"),
+        ("FA_RDNDA", "도로명상세주소"),
+        ("FA_RDNZC", "도로명우편번호"),
+        ("FA_TELNO", "전화번호"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
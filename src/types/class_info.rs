@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use super::ToQueryString;
+use super::{Endpoint, FieldLabels, ToQueryString};
 use super::{deserialize_i32_from_string, deserialize_u8_from_string};
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
@@ -77,7 +77,12 @@ impl ToQueryString for ClassInfoParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for ClassInfoParams {
+    const RESOURCE: &'static str = "classInfo";
+    type Item = ClassInfoItem;
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct ClassInfoItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -129,3 +134,20 @@ pub struct ClassInfoItem {
     /// Example: 20250901
     pub LOAD_DTM: String,
 }
+
+impl FieldLabels for ClassInfoItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("SD_SCHUL_CODE", "행정표준코드"),
+        ("SCHUL_NM", "학교명"),
+        ("AY", "학년도"),
+        ("GRADE", "학년"),
+        ("DGHT_CRSE_SC_NM", "주야과정명"),
+        ("SCHUL_CRSE_SC_NM", "학교과정명"),
+        ("ORD_SC_NM", "계열명"),
+        ("DDDEP_NM", "학과명"),
+        ("CLASS_NM", "학급명"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
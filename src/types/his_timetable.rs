@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
-use super::ToQueryString;
+use super::{Endpoint, FieldLabels, ToQueryString};
 use super::{deserialize_i32_from_string, deserialize_u8_from_string};
+use crate::timetable::{Lesson, TimetableEntry};
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -123,7 +124,35 @@ impl ToQueryString for HisTimetableParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for HisTimetableParams {
+    const RESOURCE: &'static str = "hisTimetable";
+    type Item = HisTimetableItem;
+}
+
+impl_date_range!(HisTimetableParams, TI_FROM_YMD, TI_TO_YMD);
+
+/// 고등학교시간표 항목
+///
+/// 학년도, 학년 등 문자열로 오는 숫자는 숫자로 직렬화하며, 읽을 때는 둘 다 받는다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::types::HisTimetableItem;
+///
+/// let item: HisTimetableItem = serde_json::from_value(serde_json::json!({
+///     "ATPT_OFCDC_SC_CODE": "B10", "ATPT_OFCDC_SC_NM": "서울특별시교육청",
+///     "SD_SCHUL_CODE": "7010959", "SCHUL_NM": "문현고등학교", "AY": "2023",
+///     "SEM": "2", "ALL_TI_YMD": "20230821", "GRADE": "3", "CLASS_NM": "1",
+///     "PERIO": "1", "ITRT_CNTNT": "국어", "LOAD_DTM": "20230827",
+/// }))
+/// .unwrap();
+///
+/// let json = serde_json::to_value(&item).unwrap();
+/// assert_eq!(json["AY"], 2023);
+/// assert_eq!(serde_json::from_value::<HisTimetableItem>(json).unwrap(), item);
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct HisTimetableItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -193,3 +222,31 @@ pub struct HisTimetableItem {
     /// Example: 20230827
     pub LOAD_DTM: String,
 }
+
+impl FieldLabels for HisTimetableItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("SD_SCHUL_CODE", "행정표준코드"),
+        ("SCHUL_NM", "학교명"),
+        ("AY", "학년도"),
+        ("SEM", "학기"),
+        ("ALL_TI_YMD", "시간표일자"),
+        ("DGHT_CRSE_SC_NM", "주야과정명"),
+        ("ORD_SC_NM", "계열명"),
+        ("DDDEP_NM", "학과명"),
+        ("GRADE", "학년"),
+        ("CLRM_NM", "강의실명"),
+        ("CLASS_NM", "학급명"),
+        ("PERIO", "교시"),
+        ("ITRT_CNTNT", "수업내용"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
+
+impl HisTimetableItem {
+    /// 수업내용을 과목명과 태그로 나눔
+    pub fn lesson(&self) -> Option<Lesson> {
+        TimetableEntry::lesson(self)
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(non_snake_case)]
-use super::ToQueryString;
 use super::deserialize_i32_from_f64;
+use super::{Endpoint, FieldLabels, ToQueryString};
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -32,6 +32,11 @@ impl MealServiceParams {
         }
     }
 
+    pub fn meal_type(mut self, meal_type: MealType) -> Self {
+        self.MMEAL_SC_CODE = Some(meal_type.code().to_owned());
+        self
+    }
+
     pub fn ymd(mut self, year: i32, month: u8, day: u8) -> Self {
         self.MLSV_YMD = Some(format!("{:04}{:02}{:02}", year, month, day));
         self
@@ -69,7 +74,62 @@ impl ToQueryString for MealServiceParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for MealServiceParams {
+    const RESOURCE: &'static str = "mealServiceDietInfo";
+    type Item = MealServiceItem;
+}
+
+impl_date_range!(MealServiceParams, MLSV_FROM_YMD, MLSV_TO_YMD);
+
+/// 식사코드
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MealType {
+    /// 조식
+    Breakfast,
+    /// 중식
+    Lunch,
+    /// 석식
+    Dinner,
+}
+
+impl MealType {
+    /// 식사코드로 변환
+    /// 1 | 2 | 3
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "1" => Some(MealType::Breakfast),
+            "2" => Some(MealType::Lunch),
+            "3" => Some(MealType::Dinner),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            MealType::Breakfast => "1",
+            MealType::Lunch => "2",
+            MealType::Dinner => "3",
+        }
+    }
+
+    /// 식사명
+    /// 조식 | 중식 | 석식
+    pub fn name(&self) -> &'static str {
+        match self {
+            MealType::Breakfast => "조식",
+            MealType::Lunch => "중식",
+            MealType::Dinner => "석식",
+        }
+    }
+}
+
+impl std::fmt::Display for MealType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct MealServiceItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -105,12 +165,14 @@ pub struct MealServiceItem {
     pub MLSV_FGR: i32,
 
     /// 요리명
+    /// Example: 쌀밥<br/>배추김치 (9.13.)
     pub DDISH_NM: String,
 
     /// 원산지정보
     pub ORPLC_INFO: String,
 
     /// 칼로리정보
+    /// Example: 784.4 Kcal
     pub CAL_INFO: Option<String>,
 
     /// 영양정보
@@ -128,3 +190,163 @@ pub struct MealServiceItem {
     /// Example: 20210111043017
     pub LOAD_DTM: String,
 }
+
+impl FieldLabels for MealServiceItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("SD_SCHUL_CODE", "행정표준코드"),
+        ("SCHUL_NM", "학교명"),
+        ("MMEAL_SC_CODE", "식사코드"),
+        ("MMEAL_SC_NM", "식사명"),
+        ("MLSV_YMD", "급식일자"),
+        ("MLSV_FGR", "급식인원수"),
+        ("DDISH_NM", "요리명"),
+        ("ORPLC_INFO", "원산지정보"),
+        ("CAL_INFO", "칼로리정보"),
+        ("NTR_INFO", "영양정보"),
+        ("MLSV_FROM_YMD", "급식시작일자"),
+        ("MLSV_TO_YMD", "급식종료일자"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
+
+impl MealServiceItem {
+    /// 식사코드를 [`MealType`] 으로 변환
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::types::{MealServiceItem, MealType};
+    ///
+    /// fn dinners(items: Vec<MealServiceItem>) -> Vec<MealServiceItem> {
+    ///     items
+    ///         .into_iter()
+    ///         .filter(|item| item.meal_type() == Some(MealType::Dinner))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn meal_type(&self) -> Option<MealType> {
+        MealType::from_code(&self.MMEAL_SC_CODE)
+    }
+
+    /// 요리명을 요리별로 나눔
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::types::MealServiceItem;
+    ///
+    /// fn print_allergens(item: &MealServiceItem) {
+    ///     for dish in item.dishes() {
+    ///         println!("{}: {}", dish.name, dish.allergen_names().join(", "));
+    ///     }
+    /// }
+    /// ```
+    pub fn dishes(&self) -> Vec<Dish> {
+        self.DDISH_NM
+            .split("<br/>")
+            .flat_map(|s| s.split("<br>"))
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(Dish::parse)
+            .collect()
+    }
+
+    /// 칼로리정보의 숫자
+    pub fn calories(&self) -> Option<f32> {
+        self.CAL_INFO
+            .as_deref()?
+            .trim()
+            .trim_end_matches("Kcal")
+            .trim()
+            .parse()
+            .ok()
+    }
+}
+
+/// 알레르기 유발 식품
+/// 식품의약품안전처 고시 번호 순서
+pub const ALLERGENS: [&str; 19] = [
+    "난류",
+    "우유",
+    "메밀",
+    "땅콩",
+    "대두",
+    "밀",
+    "고등어",
+    "게",
+    "새우",
+    "돼지고기",
+    "복숭아",
+    "토마토",
+    "아황산류",
+    "호두",
+    "닭고기",
+    "쇠고기",
+    "오징어",
+    "조개류",
+    "잣",
+];
+
+/// 요리명과 알레르기 유발 식품 번호
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct Dish {
+    /// Example: 배추김치
+    pub name: String,
+    /// Example: 9, 13
+    pub allergens: Vec<u8>,
+}
+
+impl Dish {
+    /// `배추김치 (9.13.)` 또는 `배추김치9.13.` 형식
+    pub fn parse(s: &str) -> Self {
+        let s = s.trim();
+        let (name, numbers) = if let Some(open) = s.rfind('(')
+            && s.ends_with(')')
+            && is_allergen_list(&s[open + 1..s.len() - 1])
+        {
+            (&s[..open], &s[open + 1..s.len() - 1])
+        } else if s.ends_with('.') {
+            let split = s
+                .trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')
+                .len();
+            (&s[..split], &s[split..])
+        } else {
+            (s, "")
+        };
+
+        Self {
+            name: name.trim().to_owned(),
+            allergens: numbers
+                .split('.')
+                .filter_map(|n| n.trim().parse().ok())
+                .collect(),
+        }
+    }
+
+    /// 알레르기 유발 식품명
+    pub fn allergen_names(&self) -> Vec<&'static str> {
+        self.allergens
+            .iter()
+            .filter_map(|n| ALLERGENS.get((*n as usize).checked_sub(1)?).copied())
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Dish {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.name)?;
+        if !self.allergens.is_empty() {
+            let numbers: Vec<String> = self.allergens.iter().map(u8::to_string).collect();
+            write!(f, " ({}.)", numbers.join("."))?;
+        }
+        Ok(())
+    }
+}
+
+fn is_allergen_list(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_digit() || c == '.' || c == ' ')
+}
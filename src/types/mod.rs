@@ -1,3 +1,26 @@
+/// 시작일자, 종료일자 필드로 [`DateRange`] 구현
+macro_rules! impl_date_range {
+    ($params:ty, $from:ident, $to:ident) => {
+        impl $crate::types::DateRange for $params {
+            fn date_range(&self) -> Option<(::chrono::NaiveDate, ::chrono::NaiveDate)> {
+                let from = $crate::types::parse_ymd(self.$from.as_deref()?)?;
+                let to = $crate::types::parse_ymd(self.$to.as_deref()?)?;
+                Some((from, to))
+            }
+
+            fn with_date_range(
+                mut self,
+                from: ::chrono::NaiveDate,
+                to: ::chrono::NaiveDate,
+            ) -> Self {
+                self.$from = Some(from.format("%Y%m%d").to_string());
+                self.$to = Some(to.format("%Y%m%d").to_string());
+                self
+            }
+        }
+    };
+}
+
 mod academy_info;
 mod class_info;
 mod class_room_info;
@@ -12,20 +35,26 @@ mod school_schedule;
 mod sps_timetable;
 
 pub use academy_info::{AcademyInfoItem, AcademyInfoParams};
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
 pub use class_info::{ClassInfoItem, ClassInfoParams};
 pub use class_room_info::{ClassRoomInfoItem, ClassRoomInfoParams};
 pub use els_timetable::{ElsTimetableItem, ElsTimetableParams};
 pub use his_timetable::{HisTimetableItem, HisTimetableParams};
-pub use meal_service::{MealServiceItem, MealServiceParams};
+pub use meal_service::{ALLERGENS, Dish, MealServiceItem, MealServiceParams, MealType};
 pub use mis_timetable::{MisTimetableItem, MisTimetableParams};
 pub use school_aflco_info::{SchoolAflcoInfoItem, SchoolAflcoInfoParams};
-pub use school_info::{SchoolInfoItem, SchoolInfoParams};
+pub use school_info::{
+    AdmissionPeriod, Coeducation, DayNight, Foundation, HighSchoolType, SchoolInfoItem,
+    SchoolInfoParams, SchoolKind,
+};
 pub use school_major_info::{SchoolMajorInfoItem, SchoolMajorInfoParams};
-pub use school_schedule::{SchoolScheduleItem, SchoolScheduleParams};
-use serde::Deserialize;
+pub use school_schedule::{
+    DayKind, GradeSet, SchoolScheduleItem, SchoolScheduleParams, events_for_grade,
+};
+use serde::{Deserialize, Serialize};
 pub use sps_timetable::{SpsTimetableItem, SpsTimetableParams};
 
-#[derive(Debug, Clone, Deserialize, Hash, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub enum YesOrNo {
     Y,
     N,
@@ -35,22 +64,120 @@ pub trait ToQueryString {
     fn to_query_string(&self) -> String;
 }
 
+/// 조회 조건과 응답 항목을 잇는 API
+pub trait Endpoint: ToQueryString {
+    /// 서비스명
+    /// Example: mealServiceDietInfo
+    const RESOURCE: &'static str;
+    type Item;
+}
+
+/// 시작일자, 종료일자로 조회하는 조건
+pub trait DateRange: Sized {
+    /// 시작일자와 종료일자가 모두 있으면 그 기간
+    fn date_range(&self) -> Option<(NaiveDate, NaiveDate)>;
+    fn with_date_range(self, from: NaiveDate, to: NaiveDate) -> Self;
+}
+
+/// 긴 조회 기간을 나누는 단위
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::NaiveDate;
+/// use neis_client::types::Chunk;
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2025, m, d).unwrap();
+///
+/// assert_eq!(
+///     Chunk::Month.split(date(1, 20), date(3, 5)),
+///     vec![
+///         (date(1, 20), date(1, 31)),
+///         (date(2, 1), date(2, 28)),
+///         (date(3, 1), date(3, 5)),
+///     ]
+/// );
+/// assert_eq!(
+///     Chunk::Week.split(date(3, 5), date(3, 12)),
+///     vec![(date(3, 5), date(3, 9)), (date(3, 10), date(3, 12))]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chunk {
+    /// 월요일 ~ 일요일
+    Week,
+    /// 1일 ~ 말일
+    Month,
+    /// 시작일부터 정해진 일수
+    Days(u32),
+}
+
+impl Chunk {
+    /// `from` ~ `to` (양끝 포함)을 나눈 구간
+    pub fn split(&self, from: NaiveDate, to: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut chunks = Vec::new();
+        let mut start = from;
+        while start <= to {
+            let end = match self {
+                Chunk::Week => start.week(Weekday::Mon).last_day(),
+                Chunk::Month => start
+                    .with_day(1)
+                    .and_then(|first| first.checked_add_months(Months::new(1)))
+                    .and_then(|next| next.pred_opt())
+                    .unwrap_or(to),
+                Chunk::Days(days) => start
+                    .checked_add_days(Days::new(u64::from(*days).max(1) - 1))
+                    .unwrap_or(to),
+            }
+            .min(to);
+            chunks.push((start, end));
+
+            match end.succ_opt() {
+                Some(next) => start = next,
+                None => break,
+            }
+        }
+        chunks
+    }
+}
+
+/// 응답 항목의 필드명과 한글 이름
+pub trait FieldLabels {
+    /// (NEIS 필드명, 한글 이름), 필드 선언 순서
+    const FIELDS: &'static [(&'static str, &'static str)];
+}
+
+/// YYYYMMDD 형식의 일자를 변환
+pub(crate) fn parse_ymd(ymd: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(ymd.trim(), "%Y%m%d").ok()
+}
+
+// NEIS 는 숫자를 문자열로 주지만, 직렬화한 항목은 숫자로 쓰므로 둘 다 읽음
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StringOrNumber {
+    String(String),
+    Number(i64),
+}
+
 fn deserialize_u8_from_string<'de, D>(deserializer: D) -> Result<u8, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    String::deserialize(deserializer)?
-        .parse()
-        .map_err(serde::de::Error::custom)
+    match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(s) => s.parse().map_err(serde::de::Error::custom),
+        StringOrNumber::Number(n) => u8::try_from(n).map_err(serde::de::Error::custom),
+    }
 }
 
 fn deserialize_i32_from_string<'de, D>(deserializer: D) -> Result<i32, D::Error>
 where
     D: serde::de::Deserializer<'de>,
 {
-    String::deserialize(deserializer)?
-        .parse()
-        .map_err(serde::de::Error::custom)
+    match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(s) => s.parse().map_err(serde::de::Error::custom),
+        StringOrNumber::Number(n) => i32::try_from(n).map_err(serde::de::Error::custom),
+    }
 }
 
 fn deserialize_i32_from_f64<'de, D>(deserializer: D) -> Result<i32, D::Error>
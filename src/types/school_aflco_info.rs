@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use super::ToQueryString;
+use super::{Endpoint, FieldLabels, ToQueryString};
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -44,7 +44,12 @@ impl ToQueryString for SchoolAflcoInfoParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for SchoolAflcoInfoParams {
+    const RESOURCE: &'static str = "schulAflcoinfo";
+    type Item = SchoolAflcoInfoItem;
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct SchoolAflcoInfoItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -74,3 +79,15 @@ pub struct SchoolAflcoInfoItem {
     /// Example: 20250901
     pub LOAD_DTM: String,
 }
+
+impl FieldLabels for SchoolAflcoInfoItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("SD_SCHUL_CODE", "행정표준코드"),
+        ("SCHUL_NM", "학교명"),
+        ("DGHT_CRSE_SC_NM", "주야과정명"),
+        ("ORD_SC_NM", "계열명"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
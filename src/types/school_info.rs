@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use super::{ToQueryString, YesOrNo};
+use super::{Endpoint, FieldLabels, ToQueryString, YesOrNo};
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +26,19 @@ impl SchoolInfoParams {
             ..Default::default()
         }
     }
+
+    pub fn office(mut self, atpt_ofcdc_sc_code: &str) -> Self {
+        self.ATPT_OFCDC_SC_CODE = Some(atpt_ofcdc_sc_code.to_owned());
+        self
+    }
+    pub fn school_kind(mut self, school_kind: SchoolKind) -> Self {
+        self.SCHUL_KND_SC_NM = Some(school_kind.as_str().to_owned());
+        self
+    }
+    pub fn foundation(mut self, foundation: Foundation) -> Self {
+        self.FOND_SC_NM = Some(foundation.as_str().to_owned());
+        self
+    }
 }
 
 impl ToQueryString for SchoolInfoParams {
@@ -55,7 +68,12 @@ impl ToQueryString for SchoolInfoParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for SchoolInfoParams {
+    const RESOURCE: &'static str = "schoolInfo";
+    type Item = SchoolInfoItem;
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct SchoolInfoItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -155,3 +173,363 @@ pub struct SchoolInfoItem {
     /// Example: 20230627
     pub LOAD_DTM: String,
 }
+
+impl FieldLabels for SchoolInfoItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("SD_SCHUL_CODE", "행정표준코드"),
+        ("SCHUL_NM", "학교명"),
+        ("ENG_SCHUL_NM", "영문학교명"),
+        ("SCHUL_KND_SC_NM", "학교종류명"),
+        ("LCTN_SC_NM", "시도명"),
+        ("JU_ORG_NM", "관할조직명"),
+        ("FOND_SC_NM", "설립명"),
+        ("ORG_RDNZC", "도로명우편번호"),
+        ("ORG_RDNMA", "도로명주소"),
+        ("ORG_RDNDA", "도로명상세주소"),
+        ("ORG_TELNO", "전화번호"),
+        ("HMPG_ADRES", "홈페이지주소"),
+        ("COEDU_SC_NM", "남녀공학구분명"),
+        ("ORG_FAXNO", "팩스번호"),
+        ("HS_SC_NM", "고등학교구분명"),
+        ("INDST_SPECL_CCCCL_EXST_YN", "산업체특별학급존재여부"),
+        ("HS_GNRL_BUSNS_SC_NM", "고등학교일반전문구분명"),
+        ("SPCLY_PURPS_HS_ORD_NM", "특수목적고등학교계열명"),
+        ("ENE_BFE_SEHF_SC_NM", "입시전후기구분명"),
+        ("DGHT_SC_NM", "주야구분명"),
+        ("FOND_YMD", "설립일자"),
+        ("FOAS_MEMRD", "개교기념일"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
+
+impl SchoolInfoItem {
+    /// 학교종류명
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::types::{SchoolInfoItem, SchoolKind};
+    ///
+    /// fn high_schools(items: &[SchoolInfoItem]) -> Vec<&SchoolInfoItem> {
+    ///     items
+    ///         .iter()
+    ///         .filter(|item| item.school_kind() == Some(SchoolKind::High))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn school_kind(&self) -> Option<SchoolKind> {
+        non_blank(self.SCHUL_KND_SC_NM.as_deref()).map(SchoolKind::from)
+    }
+
+    /// 설립명
+    pub fn foundation(&self) -> Option<Foundation> {
+        non_blank(self.FOND_SC_NM.as_deref()).map(Foundation::from)
+    }
+
+    /// 남녀공학구분명
+    pub fn coeducation(&self) -> Option<Coeducation> {
+        non_blank(Some(&self.COEDU_SC_NM)).map(Coeducation::from)
+    }
+
+    /// 고등학교구분명
+    pub fn high_school_type(&self) -> Option<HighSchoolType> {
+        non_blank(self.HS_SC_NM.as_deref()).map(HighSchoolType::from)
+    }
+
+    /// 입시전후기구분명
+    pub fn admission_period(&self) -> Option<AdmissionPeriod> {
+        non_blank(Some(&self.ENE_BFE_SEHF_SC_NM)).map(AdmissionPeriod::from)
+    }
+
+    /// 주야구분명
+    pub fn day_night(&self) -> Option<DayNight> {
+        non_blank(Some(&self.DGHT_SC_NM)).map(DayNight::from)
+    }
+}
+
+// 해당 없는 항목은 공백 문자열로 내려오는 경우가 있음
+fn non_blank(s: Option<&str>) -> Option<&str> {
+    s.map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// 학교종류명
+/// 초등학교 | 중학교 | 고등학교 | 특수학교 | 각종학교 | 고등공민학교 | 고등기술학교 | 방송통신중학교 | 방송통신고등학교
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SchoolKind {
+    /// 초등학교
+    Elementary,
+    /// 중학교
+    Middle,
+    /// 고등학교
+    High,
+    /// 특수학교
+    Special,
+    /// 각종학교
+    Miscellaneous,
+    /// 고등공민학교
+    CivicHigh,
+    /// 고등기술학교
+    TechnicalHigh,
+    /// 방송통신중학교
+    BroadcastMiddle,
+    /// 방송통신고등학교
+    BroadcastHigh,
+    /// 그 외
+    Other(String),
+}
+
+impl SchoolKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SchoolKind::Elementary => "초등학교",
+            SchoolKind::Middle => "중학교",
+            SchoolKind::High => "고등학교",
+            SchoolKind::Special => "특수학교",
+            SchoolKind::Miscellaneous => "각종학교",
+            SchoolKind::CivicHigh => "고등공민학교",
+            SchoolKind::TechnicalHigh => "고등기술학교",
+            SchoolKind::BroadcastMiddle => "방송통신중학교",
+            SchoolKind::BroadcastHigh => "방송통신고등학교",
+            SchoolKind::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for SchoolKind {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "초등학교" => SchoolKind::Elementary,
+            "중학교" => SchoolKind::Middle,
+            "고등학교" => SchoolKind::High,
+            "특수학교" => SchoolKind::Special,
+            "각종학교" => SchoolKind::Miscellaneous,
+            "고등공민학교" => SchoolKind::CivicHigh,
+            "고등기술학교" => SchoolKind::TechnicalHigh,
+            "방송통신중학교" => SchoolKind::BroadcastMiddle,
+            "방송통신고등학교" => SchoolKind::BroadcastHigh,
+            s => SchoolKind::Other(s.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for SchoolKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 설립명
+/// 국립 | 공립 | 사립
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Foundation {
+    /// 국립
+    National,
+    /// 공립
+    Public,
+    /// 사립
+    Private,
+    /// 그 외
+    Other(String),
+}
+
+impl Foundation {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Foundation::National => "국립",
+            Foundation::Public => "공립",
+            Foundation::Private => "사립",
+            Foundation::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for Foundation {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "국립" => Foundation::National,
+            "공립" => Foundation::Public,
+            "사립" => Foundation::Private,
+            s => Foundation::Other(s.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for Foundation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 남녀공학구분명
+/// 남 | 여 | 남여공학
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Coeducation {
+    /// 남
+    Boys,
+    /// 여
+    Girls,
+    /// 남여공학
+    Mixed,
+    /// 그 외
+    Other(String),
+}
+
+impl Coeducation {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Coeducation::Boys => "남",
+            Coeducation::Girls => "여",
+            Coeducation::Mixed => "남여공학",
+            Coeducation::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for Coeducation {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "남" => Coeducation::Boys,
+            "여" => Coeducation::Girls,
+            "남여공학" => Coeducation::Mixed,
+            s => Coeducation::Other(s.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for Coeducation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 고등학교구분명
+/// 일반고 | 특성화고 | 특목고 | 자율고
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HighSchoolType {
+    /// 일반고
+    General,
+    /// 특성화고
+    Vocational,
+    /// 특목고
+    SpecialPurpose,
+    /// 자율고
+    Autonomous,
+    /// 그 외
+    Other(String),
+}
+
+impl HighSchoolType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            HighSchoolType::General => "일반고",
+            HighSchoolType::Vocational => "특성화고",
+            HighSchoolType::SpecialPurpose => "특목고",
+            HighSchoolType::Autonomous => "자율고",
+            HighSchoolType::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for HighSchoolType {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "일반고" => HighSchoolType::General,
+            "특성화고" => HighSchoolType::Vocational,
+            "특목고" => HighSchoolType::SpecialPurpose,
+            "자율고" => HighSchoolType::Autonomous,
+            s => HighSchoolType::Other(s.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for HighSchoolType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 입시전후기구분명
+/// 전기 | 후기 | 전후기
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AdmissionPeriod {
+    /// 전기
+    Early,
+    /// 후기
+    Late,
+    /// 전후기
+    Both,
+    /// 그 외
+    Other(String),
+}
+
+impl AdmissionPeriod {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AdmissionPeriod::Early => "전기",
+            AdmissionPeriod::Late => "후기",
+            AdmissionPeriod::Both => "전후기",
+            AdmissionPeriod::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for AdmissionPeriod {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "전기" => AdmissionPeriod::Early,
+            "후기" => AdmissionPeriod::Late,
+            "전후기" => AdmissionPeriod::Both,
+            s => AdmissionPeriod::Other(s.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for AdmissionPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 주야구분명
+/// 주간 | 야간 | 주야간
+#[derive(Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DayNight {
+    /// 주간
+    Day,
+    /// 야간
+    Night,
+    /// 주야간
+    DayAndNight,
+    /// 그 외
+    Other(String),
+}
+
+impl DayNight {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DayNight::Day => "주간",
+            DayNight::Night => "야간",
+            DayNight::DayAndNight => "주야간",
+            DayNight::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for DayNight {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "주간" => DayNight::Day,
+            "야간" => DayNight::Night,
+            "주야간" => DayNight::DayAndNight,
+            s => DayNight::Other(s.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for DayNight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
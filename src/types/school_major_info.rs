@@ -1,5 +1,5 @@
 #![allow(non_snake_case)]
-use super::ToQueryString;
+use super::{Endpoint, FieldLabels, ToQueryString};
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -50,7 +50,12 @@ impl ToQueryString for SchoolMajorInfoParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for SchoolMajorInfoParams {
+    const RESOURCE: &'static str = "schoolMajorinfo";
+    type Item = SchoolMajorInfoItem;
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct SchoolMajorInfoItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -84,3 +89,16 @@ pub struct SchoolMajorInfoItem {
     /// Example: 20250901
     pub LOAD_DTM: String,
 }
+
+impl FieldLabels for SchoolMajorInfoItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("SD_SCHUL_CODE", "행정표준코드"),
+        ("SCHUL_NM", "학교명"),
+        ("DGHT_CRSE_SC_NM", "주야과정명"),
+        ("ORD_SC_NM", "계열명"),
+        ("DDDEP_NM", "학과명"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
@@ -1,6 +1,8 @@
 #![allow(non_snake_case)]
-use super::ToQueryString;
 use super::deserialize_i32_from_string;
+use super::parse_ymd;
+use super::{Endpoint, FieldLabels, ToQueryString};
+use chrono::NaiveDate;
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -75,7 +77,14 @@ impl ToQueryString for SchoolScheduleParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for SchoolScheduleParams {
+    const RESOURCE: &'static str = "SchoolSchedule";
+    type Item = SchoolScheduleItem;
+}
+
+impl_date_range!(SchoolScheduleParams, AA_FROM_YMD, AA_TO_YMD);
+
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct SchoolScheduleItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -107,7 +116,7 @@ pub struct SchoolScheduleItem {
     pub SCHUL_CRSE_SC_NM: Option<String>,
 
     /// 수업공제일명
-    /// Example: 휴업일
+    /// 해당없음 | 휴업일 | 공휴일
     pub SBTR_DD_SC_NM: Option<String>,
 
     /// 학사일자
@@ -146,16 +155,283 @@ pub struct SchoolScheduleItem {
     pub LOAD_DTM: String,
 }
 
+impl FieldLabels for SchoolScheduleItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("SD_SCHUL_CODE", "행정표준코드"),
+        ("SCHUL_NM", "학교명"),
+        ("AY", "학년도"),
+        ("DGHT_CRSE_SC_NM", "주야과정명"),
+        ("SCHUL_CRSE_SC_NM", "학교과정명"),
+        ("SBTR_DD_SC_NM", "수업공제일명"),
+        ("AA_YMD", "학사일자"),
+        ("EVENT_NM", "행사명"),
+        ("EVENT_CNTNT", "행사내용"),
+        ("ONE_GRADE_EVENT_YN", "1학년행사여부"),
+        ("TW_GRADE_EVENT_YN", "2학년행사여부"),
+        ("THREE_GRADE_EVENT_YN", "3학년행사여부"),
+        ("FR_GRADE_EVENT_YN", "4학년행사여부"),
+        ("FIV_GRADE_EVENT_YN", "5학년행사여부"),
+        ("SIX_GRADE_EVENT_YN", "6학년행사여부"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
+
 impl SchoolScheduleItem {
+    /// 학사일자
+    pub fn date(&self) -> Option<NaiveDate> {
+        parse_ymd(&self.AA_YMD)
+    }
+
+    /// 수업공제일명
+    pub fn day_kind(&self) -> DayKind {
+        DayKind::from(self.SBTR_DD_SC_NM.as_deref().unwrap_or_default())
+    }
+
     pub fn is_event_for_grade(&self, grade: u8) -> bool {
-        match grade {
-            1 => self.ONE_GRADE_EVENT_YN == "Y",
-            2 => self.TW_GRADE_EVENT_YN == "Y",
-            3 => self.THREE_GRADE_EVENT_YN == "Y",
-            4 => self.FR_GRADE_EVENT_YN == "Y",
-            5 => self.FIV_GRADE_EVENT_YN == "Y",
-            6 => self.SIX_GRADE_EVENT_YN == "Y",
-            _ => false,
+        self.grades().contains(grade)
+    }
+
+    /// 학년별 행사여부
+    pub fn grades(&self) -> GradeSet {
+        let mut set = GradeSet::empty();
+        let flags = [
+            &self.ONE_GRADE_EVENT_YN,
+            &self.TW_GRADE_EVENT_YN,
+            &self.THREE_GRADE_EVENT_YN,
+            &self.FR_GRADE_EVENT_YN,
+            &self.FIV_GRADE_EVENT_YN,
+            &self.SIX_GRADE_EVENT_YN,
+        ];
+        for (grade, flag) in (1..).zip(flags) {
+            match flag.trim() {
+                "Y" => set.insert(grade),
+                "N" => set.remove(grade),
+                _ => {} // * : 해당 학년 없음
+            }
         }
+        set
+    }
+}
+
+/// 수업공제일명
+/// 해당없음 | 휴업일 | 공휴일
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum DayKind {
+    /// 해당없음
+    SchoolDay,
+    /// 휴업일
+    Closed,
+    /// 공휴일
+    Holiday,
+    /// 그 외
+    Other(String),
+}
+
+impl DayKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DayKind::SchoolDay => "해당없음",
+            DayKind::Closed => "휴업일",
+            DayKind::Holiday => "공휴일",
+            DayKind::Other(s) => s,
+        }
+    }
+
+    /// 수업이 있는 날인지 여부
+    pub fn has_classes(&self) -> bool {
+        *self == DayKind::SchoolDay
+    }
+}
+
+impl From<&str> for DayKind {
+    fn from(s: &str) -> Self {
+        match s.trim() {
+            "" | "해당없음" => DayKind::SchoolDay,
+            "휴업일" => DayKind::Closed,
+            "공휴일" => DayKind::Holiday,
+            s => DayKind::Other(s.to_owned()),
+        }
+    }
+}
+
+impl std::fmt::Display for DayKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 해당 학년의 행사만 반환
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::types::{SchoolScheduleItem, events_for_grade};
+///
+/// fn first_grade_events(items: &[SchoolScheduleItem]) -> Vec<&str> {
+///     events_for_grade(items, 1)
+///         .map(|item| item.EVENT_NM.as_str())
+///         .collect()
+/// }
+/// ```
+pub fn events_for_grade(
+    items: &[SchoolScheduleItem],
+    grade: u8,
+) -> impl Iterator<Item = &SchoolScheduleItem> {
+    items
+        .iter()
+        .filter(move |item| item.grades().contains(grade))
+}
+
+/// 1~6학년 행사여부
+///
+/// 학년마다 행사 있음(Y), 행사 없음(N), 해당 학년 없음(*) 세 가지 상태를 가진다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::types::GradeSet;
+///
+/// let mut set = GradeSet::empty();
+/// set.insert(1);
+/// set.remove(2);
+///
+/// assert_eq!(set.applies_to(1), Some(true));
+/// assert_eq!(set.applies_to(2), Some(false));
+/// assert_eq!(set.applies_to(3), None);
+///
+/// let other: GradeSet = [2, 3].into_iter().collect();
+/// assert_eq!((set | other).iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+/// assert!((set & other).is_empty());
+/// ```
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub struct GradeSet {
+    /// 행사가 있는 학년 (Y)
+    grades: u8,
+    /// 학년이 존재하는 학년 (Y | N)
+    applicable: u8,
+}
+
+impl GradeSet {
+    pub const MAX_GRADE: u8 = 6;
+
+    pub const fn empty() -> Self {
+        Self {
+            grades: 0,
+            applicable: 0,
+        }
+    }
+
+    pub const fn all() -> Self {
+        Self {
+            grades: 0b0011_1111,
+            applicable: 0b0011_1111,
+        }
+    }
+
+    fn bit(grade: u8) -> u8 {
+        if (1..=Self::MAX_GRADE).contains(&grade) {
+            1 << (grade - 1)
+        } else {
+            0
+        }
+    }
+
+    /// 행사 있음(Y) 으로 설정
+    pub fn insert(&mut self, grade: u8) {
+        let bit = Self::bit(grade);
+        self.grades |= bit;
+        self.applicable |= bit;
+    }
+
+    /// 행사 없음(N) 으로 설정
+    pub fn remove(&mut self, grade: u8) {
+        let bit = Self::bit(grade);
+        self.grades &= !bit;
+        self.applicable |= bit;
+    }
+
+    /// 해당 학년이 없으면(*) `None`
+    pub fn applies_to(&self, grade: u8) -> Option<bool> {
+        let bit = Self::bit(grade);
+        if self.applicable & bit == 0 {
+            None
+        } else {
+            Some(self.grades & bit != 0)
+        }
+    }
+
+    pub fn contains(&self, grade: u8) -> bool {
+        self.applies_to(grade) == Some(true)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.grades == 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.grades.count_ones() as usize
+    }
+
+    /// 행사가 있는 학년
+    pub fn iter(&self) -> impl Iterator<Item = u8> + use<> {
+        let grades = self.grades;
+        (1..=Self::MAX_GRADE).filter(move |grade| grades & Self::bit(*grade) != 0)
+    }
+
+    pub fn union(&self, other: &GradeSet) -> GradeSet {
+        GradeSet {
+            grades: self.grades | other.grades,
+            applicable: self.applicable | other.applicable,
+        }
+    }
+
+    pub fn intersection(&self, other: &GradeSet) -> GradeSet {
+        GradeSet {
+            grades: self.grades & other.grades,
+            applicable: self.applicable & other.applicable,
+        }
+    }
+
+    pub fn difference(&self, other: &GradeSet) -> GradeSet {
+        GradeSet {
+            grades: self.grades & !other.grades,
+            applicable: self.applicable,
+        }
+    }
+}
+
+impl FromIterator<u8> for GradeSet {
+    fn from_iter<I: IntoIterator<Item = u8>>(iter: I) -> Self {
+        let mut set = GradeSet::empty();
+        for grade in iter {
+            set.insert(grade);
+        }
+        set
+    }
+}
+
+impl std::ops::BitOr for GradeSet {
+    type Output = GradeSet;
+
+    fn bitor(self, rhs: GradeSet) -> GradeSet {
+        self.union(&rhs)
+    }
+}
+
+impl std::ops::BitAnd for GradeSet {
+    type Output = GradeSet;
+
+    fn bitand(self, rhs: GradeSet) -> GradeSet {
+        self.intersection(&rhs)
+    }
+}
+
+impl std::ops::Sub for GradeSet {
+    type Output = GradeSet;
+
+    fn sub(self, rhs: GradeSet) -> GradeSet {
+        self.difference(&rhs)
     }
 }
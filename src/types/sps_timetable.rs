@@ -1,6 +1,7 @@
 #![allow(non_snake_case)]
-use super::ToQueryString;
+use super::{Endpoint, FieldLabels, ToQueryString};
 use super::{deserialize_i32_from_string, deserialize_u8_from_string};
+use crate::timetable::{Lesson, TimetableEntry};
 use form_urlencoded::Serializer;
 use serde::{Deserialize, Serialize};
 
@@ -121,7 +122,14 @@ impl ToQueryString for SpsTimetableParams {
     }
 }
 
-#[derive(Debug, Clone, Deserialize, Hash)]
+impl Endpoint for SpsTimetableParams {
+    const RESOURCE: &'static str = "spsTimetable";
+    type Item = SpsTimetableItem;
+}
+
+impl_date_range!(SpsTimetableParams, TI_FROM_YMD, TI_TO_YMD);
+
+#[derive(Debug, Clone, Deserialize, Serialize, Hash, PartialEq, Eq)]
 pub struct SpsTimetableItem {
     /// 시도교육청코드
     /// B10 | C10 | D10 | E10 | F10 | G10 | H10 | I10 | J10 | K10 | M10 | N10 | P10 | Q10 | R10 | S10 | T10 | V10
@@ -183,3 +191,29 @@ pub struct SpsTimetableItem {
     /// Example: 20230827
     pub LOAD_DTM: String,
 }
+
+impl FieldLabels for SpsTimetableItem {
+    const FIELDS: &'static [(&'static str, &'static str)] = &[
+        ("ATPT_OFCDC_SC_CODE", "시도교육청코드"),
+        ("ATPT_OFCDC_SC_NM", "시도교육청명"),
+        ("SD_SCHUL_CODE", "행정표준코드"),
+        ("SCHUL_NM", "학교명"),
+        ("AY", "학년도"),
+        ("SEM", "학기"),
+        ("ALL_TI_YMD", "시간표일자"),
+        ("SCHUL_CRSE_SC_NM", "학교과정명"),
+        ("GRADE", "학년"),
+        ("CLRM_NM", "강의실명"),
+        ("CLASS_NM", "학급명"),
+        ("PERIO", "교시"),
+        ("ITRT_CNTNT", "수업내용"),
+        ("LOAD_DTM", "수정일자"),
+    ];
+}
+
+impl SpsTimetableItem {
+    /// 수업내용을 과목명과 태그로 나눔
+    pub fn lesson(&self) -> Option<Lesson> {
+        TimetableEntry::lesson(self)
+    }
+}
@@ -0,0 +1,319 @@
+//! 수정일자(`LOAD_DTM`) 워터마크로 새로 생기거나 바뀐 항목만 골라내기
+
+use crate::client::ExtractFromResponse;
+use crate::types::*;
+use crate::{Error, NeisClient};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 수정일자가 있는 응답 항목
+pub trait Modified {
+    /// 수정일자
+    fn load_dtm(&self) -> &str;
+}
+
+impl Modified for SchoolInfoItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for ClassInfoItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for SchoolMajorInfoItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for SchoolAflcoInfoItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for SchoolScheduleItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for ElsTimetableItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for MisTimetableItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for HisTimetableItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for SpsTimetableItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for ClassRoomInfoItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for AcademyInfoItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+impl Modified for MealServiceItem {
+    fn load_dtm(&self) -> &str {
+        &self.LOAD_DTM
+    }
+}
+
+/// (API, 조회 조건) 별로 마지막으로 반영한 가장 최근 수정일자를 저장
+///
+/// 조회 조건은 학교와 기간을 함께 담은 쿼리 문자열로, 조건마다 워터마크를 따로 둔다.
+pub trait WatermarkStore {
+    fn get(&self, endpoint: &str, query: &str) -> Result<Option<String>, Error>;
+    fn set(&mut self, endpoint: &str, query: &str, load_dtm: &str) -> Result<(), Error>;
+}
+
+/// 프로세스 안에서만 유지되는 저장소
+#[derive(Debug, Clone, Default)]
+pub struct MemoryWatermarks {
+    // API → 조회 조건 → 수정일자
+    marks: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl MemoryWatermarks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl WatermarkStore for MemoryWatermarks {
+    fn get(&self, endpoint: &str, query: &str) -> Result<Option<String>, Error> {
+        Ok(self
+            .marks
+            .get(endpoint)
+            .and_then(|queries| queries.get(query))
+            .cloned())
+    }
+
+    fn set(&mut self, endpoint: &str, query: &str, load_dtm: &str) -> Result<(), Error> {
+        self.marks
+            .entry(endpoint.to_owned())
+            .or_default()
+            .insert(query.to_owned(), load_dtm.to_owned());
+        Ok(())
+    }
+}
+
+/// JSON 파일 저장소
+///
+/// 갱신할 때마다 임시 파일에 쓴 뒤 이름을 바꿔, 중간에 중단되어도 이전 내용이 남는다.
+#[derive(Debug, Clone)]
+pub struct FileWatermarks {
+    path: PathBuf,
+    memory: MemoryWatermarks,
+}
+
+impl FileWatermarks {
+    /// 파일이 없으면 빈 저장소
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let marks = match fs::read(&path) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(err.into()),
+        };
+
+        Ok(Self {
+            path,
+            memory: MemoryWatermarks { marks },
+        })
+    }
+
+    fn save(&self) -> Result<(), Error> {
+        let tmp = self.path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(&self.memory.marks)?)?;
+        fs::rename(&tmp, &self.path)?;
+        Ok(())
+    }
+}
+
+impl WatermarkStore for FileWatermarks {
+    fn get(&self, endpoint: &str, query: &str) -> Result<Option<String>, Error> {
+        self.memory.get(endpoint, query)
+    }
+
+    fn set(&mut self, endpoint: &str, query: &str, load_dtm: &str) -> Result<(), Error> {
+        self.memory.set(endpoint, query, load_dtm)?;
+        self.save()
+    }
+}
+
+/// 수정일자 워터마크로 새로 생기거나 바뀐 항목만 골라낸다.
+///
+/// NEIS API 는 수정일자로 조회할 수 없으므로 항목은 모두 받되,
+/// 워터마크보다 나중에 수정된 항목만 돌려준다.
+/// 워터마크는 골라낸 항목을 저장한 뒤 [`Incremental::commit`] 을 불러야 옮겨지므로,
+/// 그 전에 중단되면 다음 실행에서 같은 항목을 다시 받는다.
+///
+/// 워터마크는 조회 조건별로 따로 두므로, 기간을 바꿔 조회하면 그 기간의 항목을 처음부터 다시 돌려준다.
+/// 조건이 같으면 이전 조회에 없던 항목도 워터마크보다 수정일자가 늦어야 돌려준다.
+///
+/// 수정일자를 `YYYYMMDD` 로 일자까지만 주는 API 는 워터마크와 같은 날 다시 바뀐 항목을 돌려주지 않는다.
+/// 같은 날 여러 번 고쳐지는 자료를 놓치면 안 되면 워터마크 없이 전체를 비교해야 한다.
+///
+/// # Example
+///
+/// ```rust
+/// use neis_client::types::MealServiceItem;
+/// use neis_client::watermark::{Incremental, MemoryWatermarks};
+/// use neis_client::Error;
+///
+/// fn apply(
+///     sync: &mut Incremental<MemoryWatermarks>,
+///     items: Vec<MealServiceItem>,
+///     save: impl Fn(&[MealServiceItem]) -> Result<(), Error>,
+/// ) -> Result<(), Error> {
+///     let changed = sync.filter("mealServiceDietInfo", "SD_SCHUL_CODE=7010959", items)?;
+///     match save(&changed) {
+///         Ok(()) => sync.commit(),
+///         // 다음 조회에서 같은 항목을 다시 돌려준다
+///         Err(err) => {
+///             sync.rollback();
+///             Err(err)
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Incremental<S> {
+    store: S,
+    // commit 하지 않은 (API, 조회 조건) 별 가장 최근 수정일자
+    pending: BTreeMap<(String, String), String>,
+}
+
+impl<S: WatermarkStore> Incremental<S> {
+    pub fn new(store: S) -> Self {
+        Self {
+            store,
+            pending: BTreeMap::new(),
+        }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    pub fn store_mut(&mut self) -> &mut S {
+        &mut self.store
+    }
+
+    pub fn into_store(self) -> S {
+        self.store
+    }
+
+    /// 워터마크보다 나중에 수정된 항목
+    ///
+    /// 워터마크는 바로 옮기지 않고 [`Incremental::commit`] 때 반영한다.
+    pub fn filter<T: Modified>(
+        &mut self,
+        endpoint: &str,
+        query: &str,
+        items: Vec<T>,
+    ) -> Result<Vec<T>, Error> {
+        let watermark = self.store.get(endpoint, query)?.map(|s| pad(&s));
+
+        let changed: Vec<T> = match &watermark {
+            Some(watermark) => items
+                .into_iter()
+                .filter(|item| pad(item.load_dtm()) > *watermark)
+                .collect(),
+            None => items,
+        };
+
+        if let Some(newest) = changed.iter().map(|item| pad(item.load_dtm())).max() {
+            let pending = self
+                .pending
+                .entry((endpoint.to_owned(), query.to_owned()))
+                .or_default();
+            if newest > *pending {
+                *pending = newest;
+            }
+        }
+
+        Ok(changed)
+    }
+
+    /// 지금까지 골라낸 항목의 가장 최근 수정일자로 워터마크를 옮긴다.
+    pub fn commit(&mut self) -> Result<(), Error> {
+        while let Some(((endpoint, query), newest)) = self.pending.pop_first() {
+            if let Err(err) = self.store.set(&endpoint, &query, &newest) {
+                self.pending.insert((endpoint, query), newest);
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// 골라낸 항목을 반영하지 않고 버린다. 다음 조회에서 다시 돌려준다.
+    pub fn rollback(&mut self) {
+        self.pending.clear();
+    }
+
+    /// 조회 조건에 해당하는 항목 중 워터마크보다 나중에 수정된 항목
+    ///
+    /// 워터마크는 `params` 의 쿼리 문자열(학교, 기간 등)별로 나눈다.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use neis_client::watermark::{FileWatermarks, Incremental};
+    /// use neis_client::{types::SchoolScheduleParams, Error, NeisClient};
+    ///
+    /// # fn save<T>(items: &[T]) -> Result<(), Error> { Ok(()) }
+    /// # async fn foo() -> Result<(), Error> {
+    /// let api_key = std::env::var("NEIS_API_KEY").unwrap();
+    /// let client = NeisClient::new(&api_key);
+    ///
+    /// let mut sync = Incremental::new(FileWatermarks::open("watermarks.json")?);
+    /// let params = SchoolScheduleParams::new("B10", "7010959");
+    /// let changed = sync.fetch(&client, params).await?;
+    /// save(&changed)?;
+    /// sync.commit()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch<P>(&mut self, client: &NeisClient, params: P) -> Result<Vec<P::Item>, Error>
+    where
+        P: Endpoint,
+        P::Item: Modified + ExtractFromResponse,
+    {
+        let query = params.to_query_string();
+        let items = client.fetch(params).await?;
+        self.filter(P::RESOURCE, &query, items)
+    }
+}
+
+// YYYYMMDD 와 YYYYMMDDHHMMSS 를 함께 비교할 수 있게 맞춤
+fn pad(load_dtm: &str) -> String {
+    format!("{:0<14}", load_dtm.trim())
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "arrow")]
+
+mod common;
+
+use common::meal;
+use neis_client::export::arrow::ToRecordBatch;
+use neis_client::types::MealServiceItem;
+use serde_json::json;
+
+#[test]
+fn meal_columns_use_typed_arrow_types() {
+    let items = [
+        meal(json!({"MLSV_YMD": "20210104"})),
+        meal(json!({"MLSV_YMD": "20210105"})),
+    ];
+    let batch = MealServiceItem::to_record_batch(&items).unwrap();
+    let schema = batch.schema();
+    let data_type = |name| {
+        schema
+            .field_with_name(name)
+            .unwrap()
+            .data_type()
+            .to_string()
+    };
+
+    assert_eq!(batch.num_rows(), 2);
+    assert_eq!(data_type("MLSV_YMD"), "Date32");
+    assert_eq!(data_type("MLSV_FGR"), "Int32");
+}
@@ -0,0 +1,45 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::schedule;
+use neis_client::calendar::{PeriodAnalyzer, PeriodKind, SchoolCalendar};
+use serde_json::json;
+
+fn date(month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(2024, month, day).unwrap()
+}
+
+#[test]
+fn school_days_skip_weekends_and_holidays() {
+    let calendar = SchoolCalendar::new(vec![
+        schedule(json!({"AA_YMD": "20240301", "EVENT_NM": "삼일절", "SBTR_DD_SC_NM": "공휴일"})),
+        schedule(json!({"AA_YMD": "20240304", "EVENT_NM": "입학식"})),
+    ]);
+
+    assert!(!calendar.is_school_day(date(3, 1)));
+    assert!(!calendar.is_school_day(date(3, 2)));
+    assert!(calendar.is_school_day(date(3, 4)));
+    assert_eq!(calendar.next_school_day(date(3, 1)), Some(date(3, 4)));
+    assert_eq!(calendar.count_school_days(date(3, 1), date(3, 8)), 5);
+}
+
+#[test]
+fn periods_group_exams_and_vacations() {
+    let items = vec![
+        schedule(json!({"AA_YMD": "20240426", "EVENT_NM": "1학기 중간고사"})),
+        schedule(json!({"AA_YMD": "20240429", "EVENT_NM": "1학기 중간고사"})),
+        schedule(json!({"AA_YMD": "20240430", "EVENT_NM": "1학기 중간고사"})),
+        schedule(json!({"AA_YMD": "20240719", "EVENT_NM": "여름방학식"})),
+        schedule(json!({"AA_YMD": "20240819", "EVENT_NM": "개학식"})),
+    ];
+
+    let periods = PeriodAnalyzer::default().analyze(&items);
+
+    let exam = &periods[0];
+    assert_eq!(exam.kind, PeriodKind::MidtermExam);
+    assert_eq!((exam.start, exam.end), (date(4, 26), date(4, 30)));
+
+    let vacation = &periods[1];
+    assert_eq!(vacation.kind, PeriodKind::Vacation);
+    assert_eq!((vacation.start, vacation.end), (date(7, 20), date(8, 18)));
+}
@@ -0,0 +1,85 @@
+//! 통합 테스트에서 함께 쓰는 NEIS 응답 항목
+//!
+//! 항목마다 전체 필드를 채운 기본값에 테스트에서 바꿀 필드만 덮어쓴다.
+#![allow(dead_code)]
+
+use neis_client::types::*;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+fn item<T: DeserializeOwned>(mut base: Value, fields: Value) -> T {
+    if let (Some(base), Value::Object(fields)) = (base.as_object_mut(), fields) {
+        base.extend(fields);
+    }
+    serde_json::from_value(base).unwrap()
+}
+
+/// 학사일정
+pub fn schedule(fields: Value) -> SchoolScheduleItem {
+    let base = json!({
+        "ATPT_OFCDC_SC_CODE": "B10", "ATPT_OFCDC_SC_NM": "서울특별시교육청",
+        "SD_SCHUL_CODE": "7010959", "SCHUL_NM": "문현고등학교", "AY": "2024",
+        "SBTR_DD_SC_NM": "해당없음", "AA_YMD": "20240304", "EVENT_NM": "", "EVENT_CNTNT": "",
+        "ONE_GRADE_EVENT_YN": "Y", "TW_GRADE_EVENT_YN": "Y", "THREE_GRADE_EVENT_YN": "Y",
+        "FR_GRADE_EVENT_YN": "*", "FIV_GRADE_EVENT_YN": "*", "SIX_GRADE_EVENT_YN": "*",
+        "LOAD_DTM": "20240101",
+    });
+    item(base, fields)
+}
+
+/// 초등학교시간표
+pub fn els_timetable(fields: Value) -> ElsTimetableItem {
+    let base = json!({
+        "ATPT_OFCDC_SC_CODE": "B10", "ATPT_OFCDC_SC_NM": "서울특별시교육청",
+        "SD_SCHUL_CODE": "7130126", "SCHUL_NM": "서울문현초등학교", "AY": "2023",
+        "SEM": "2", "ALL_TI_YMD": "20230828", "GRADE": "6", "CLASS_NM": "1",
+        "PERIO": "1", "ITRT_CNTNT": "국어", "LOAD_DTM": "20230903",
+    });
+    item(base, fields)
+}
+
+/// 중학교시간표
+pub fn mis_timetable(fields: Value) -> MisTimetableItem {
+    let base = json!({
+        "ATPT_OFCDC_SC_CODE": "B10", "ATPT_OFCDC_SC_NM": "서울특별시교육청",
+        "SD_SCHUL_CODE": "7130177", "SCHUL_NM": "문현중학교", "AY": "2023",
+        "SEM": "2", "ALL_TI_YMD": "20230821", "GRADE": "3", "CLASS_NM": "1",
+        "PERIO": "1", "ITRT_CNTNT": "국어", "LOAD_DTM": "20230827",
+    });
+    item(base, fields)
+}
+
+/// 고등학교시간표
+pub fn his_timetable(fields: Value) -> HisTimetableItem {
+    let base = json!({
+        "ATPT_OFCDC_SC_CODE": "B10", "ATPT_OFCDC_SC_NM": "서울특별시교육청",
+        "SD_SCHUL_CODE": "7010959", "SCHUL_NM": "문현고등학교", "AY": "2023",
+        "SEM": "2", "ALL_TI_YMD": "20230821", "GRADE": "3", "CLRM_NM": "301",
+        "CLASS_NM": "1", "PERIO": "1", "ITRT_CNTNT": "국어", "LOAD_DTM": "20230827",
+    });
+    item(base, fields)
+}
+
+/// 급식식단정보
+pub fn meal(fields: Value) -> MealServiceItem {
+    let base = json!({
+        "ATPT_OFCDC_SC_CODE": "B10", "ATPT_OFCDC_SC_NM": "서울특별시교육청",
+        "SD_SCHUL_CODE": "7010959", "SCHUL_NM": "문현고등학교",
+        "MMEAL_SC_CODE": "2", "MMEAL_SC_NM": "중식", "MLSV_YMD": "20210104", "MLSV_FGR": 498.0,
+        "DDISH_NM": "쌀밥<br/>배추김치 (9.13.)", "ORPLC_INFO": "", "CAL_INFO": "784.4 Kcal",
+        "MLSV_FROM_YMD": "20210104", "MLSV_TO_YMD": "20210104", "LOAD_DTM": "20210111043017",
+    });
+    item(base, fields)
+}
+
+/// 학급정보
+pub fn class_info(fields: Value) -> ClassInfoItem {
+    let base = json!({
+        "ATPT_OFCDC_SC_CODE": "B10", "ATPT_OFCDC_SC_NM": "서울특별시교육청",
+        "SD_SCHUL_CODE": "7010959", "SCHUL_NM": "문현고등학교", "AY": "2024",
+        "GRADE": "1", "DGHT_CRSE_SC_NM": "주간", "SCHUL_CRSE_SC_NM": "고등학교",
+        "ORD_SC_NM": "일반계", "DDDEP_NM": "일반학과", "CLASS_NM": "1",
+        "LOAD_DTM": "20240301",
+    });
+    item(base, fields)
+}
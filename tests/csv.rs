@@ -0,0 +1,31 @@
+#![cfg(feature = "csv")]
+
+mod common;
+
+use common::class_info;
+use neis_client::export::csv::{CsvExport, Header};
+use serde_json::json;
+
+#[test]
+fn korean_header_and_rows() {
+    let items = vec![
+        class_info(json!({"CLASS_NM": "1"})),
+        class_info(json!({"CLASS_NM": "2"})),
+    ];
+    let csv = CsvExport::new()
+        .header(Header::Korean)
+        .to_string(&items)
+        .unwrap();
+
+    let mut lines = csv.lines();
+    assert!(
+        lines
+            .next()
+            .unwrap()
+            .starts_with("시도교육청코드,시도교육청명,행정표준코드,학교명,학년도")
+    );
+    assert_eq!(
+        lines.next().unwrap(),
+        "B10,서울특별시교육청,7010959,문현고등학교,2024,1,주간,고등학교,일반계,일반학과,1,20240301"
+    );
+}
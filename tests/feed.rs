@@ -0,0 +1,16 @@
+#![cfg(feature = "feed")]
+
+mod common;
+
+use common::meal;
+use neis_client::export::feed::MealFeed;
+use serde_json::json;
+
+#[test]
+fn rss_lists_dishes_with_allergens() {
+    let feed = MealFeed::new("문현고등학교 급식", "https://munhyeon.sen.hs.kr");
+    let rss = feed.to_rss(&[meal(json!({"MLSV_YMD": "20210104"}))]);
+
+    assert!(rss.contains("<title>2021-01-04 중식</title>"));
+    assert!(rss.contains("배추김치 (새우, 아황산류)&lt;br/&gt;칼로리: 784.4 Kcal"));
+}
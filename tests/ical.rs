@@ -0,0 +1,36 @@
+#![cfg(feature = "ical")]
+
+mod common;
+
+use common::{his_timetable, schedule};
+use neis_client::export::ical::Calendar;
+use neis_client::timetable::BellSchedule;
+use serde_json::json;
+
+#[test]
+fn school_schedule_becomes_all_day_events() {
+    let items = vec![schedule(json!({
+        "AA_YMD": "20240304", "EVENT_NM": "입학식",
+        "TW_GRADE_EVENT_YN": "N", "THREE_GRADE_EVENT_YN": "N",
+    }))];
+    let ics = Calendar::from_school_schedule(&items).to_string();
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.contains("DTSTART;VALUE=DATE:20240304\r\n"));
+    assert!(ics.contains("DTEND;VALUE=DATE:20240305\r\n"));
+    assert!(ics.contains("SUMMARY:입학식\r\n"));
+    assert!(ics.contains("CATEGORIES:1학년\r\n"));
+}
+
+#[test]
+fn timetable_becomes_timed_events() {
+    let lesson = his_timetable(json!({"PERIO": "1", "ITRT_CNTNT": "[보강]음악 감상과 비평"}));
+    let items = vec![lesson.clone(), lesson];
+    let ics = Calendar::from_timetable(&items, &BellSchedule::high()).to_string();
+
+    assert!(ics.contains("UID:7010959-20230821-3-1-1@open.neis.go.kr\r\n"));
+    assert!(ics.contains("DTSTART;TZID=Asia/Seoul:20230821T084000\r\n"));
+    assert!(ics.contains("SUMMARY:음악 감상과 비평\r\n"));
+    assert!(ics.contains("LOCATION:301\r\n"));
+    assert_eq!(ics.matches("BEGIN:VEVENT").count(), 1);
+}
@@ -0,0 +1,17 @@
+mod common;
+
+use common::meal;
+use serde_json::json;
+
+#[test]
+fn dishes_split_names_and_allergens() {
+    let item = meal(json!({"DDISH_NM": "쌀밥<br/>배추김치 (9.13.)"}));
+    let dishes = item.dishes();
+
+    assert_eq!(dishes.len(), 2);
+    assert_eq!(dishes[0].name, "쌀밥");
+    assert!(dishes[0].allergens.is_empty());
+    assert_eq!(dishes[1].name, "배추김치");
+    assert_eq!(dishes[1].allergens, vec![9, 13]);
+    assert_eq!(dishes[1].allergen_names(), vec!["새우", "아황산류"]);
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "sync")]
+
+mod common;
+
+use common::meal;
+use neis_client::sync::SqliteMirror;
+use serde_json::json;
+
+#[test]
+fn upsert_counts_inserted_updated_and_unchanged_rows() {
+    let item =
+        |ddish_nm: &str, load_dtm: &str| meal(json!({"DDISH_NM": ddish_nm, "LOAD_DTM": load_dtm}));
+    let mut mirror = SqliteMirror::open_in_memory().unwrap();
+    mirror.create_tables().unwrap();
+
+    let report = mirror.upsert(&[item("쌀밥", "20210111043017")]).unwrap();
+    assert_eq!(report.inserted, 1);
+
+    let report = mirror.upsert(&[item("쌀밥", "20210111043017")]).unwrap();
+    assert_eq!(report.unchanged, 1);
+
+    let report = mirror.upsert(&[item("쌀밥", "20210112043017")]).unwrap();
+    assert_eq!(report.updated, 1);
+
+    // 수정일자가 같아도 내용이 바뀌면 덮어씀
+    let report = mirror.upsert(&[item("잡곡밥", "20210112043017")]).unwrap();
+    assert_eq!(report.updated, 1);
+
+    let count: i64 = mirror
+        .connection()
+        .query_row("SELECT COUNT(*) FROM meal_service", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(count, 1);
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use chrono::NaiveDate;
+use common::{els_timetable, mis_timetable};
+use neis_client::timetable::{TimetableChange, TimetableGrid, diff};
+use serde_json::json;
+
+#[test]
+fn grid_merges_duplicate_rows() {
+    let items = vec![
+        els_timetable(json!({"ALL_TI_YMD": "20230828", "PERIO": "1", "ITRT_CNTNT": "국어"})),
+        els_timetable(json!({"ALL_TI_YMD": "20230828", "PERIO": "1", "ITRT_CNTNT": "국어"})),
+        els_timetable(json!({"ALL_TI_YMD": "20230829", "PERIO": "2", "ITRT_CNTNT": "[보강]과학"})),
+    ];
+
+    let grid = TimetableGrid::from_entries(&items, 6, "1").unwrap();
+    assert_eq!(grid.periods(), 2);
+    assert_eq!(
+        grid.to_markdown(),
+        "| 교시 | 월 08/28 | 화 08/29 | 수 08/30 | 목 08/31 | 금 09/01 |\n\
+         |---|---|---|---|---|---|\n\
+         | 1 | 국어 |  |  |  |  |\n\
+         | 2 |  | [보강]과학 |  |  |  |\n"
+    );
+}
+
+#[test]
+fn diff_compares_each_class_slot() {
+    let lesson = |class_nm: &str, perio: &str, content: &str| {
+        mis_timetable(json!({"CLASS_NM": class_nm, "PERIO": perio, "ITRT_CNTNT": content}))
+    };
+    let before = vec![
+        lesson("1", "1", "국어"),
+        lesson("1", "2", "수학"),
+        lesson("2", "2", "수학"),
+    ];
+    let after = vec![
+        lesson("1", "1", "국어"),
+        lesson("1", "2", "[보강]영어"),
+        lesson("1", "3", "과학"),
+        lesson("2", "2", "수학"),
+    ];
+
+    let date = NaiveDate::from_ymd_opt(2023, 8, 21).unwrap();
+    assert_eq!(
+        diff(&before, &after),
+        vec![
+            TimetableChange::Changed {
+                date,
+                perio: 2,
+                grade: 3,
+                class_nm: "1".to_owned(),
+                before: "수학".to_owned(),
+                after: "[보강]영어".to_owned(),
+            },
+            TimetableChange::Added {
+                date,
+                perio: 3,
+                grade: 3,
+                class_nm: "1".to_owned(),
+                after: "과학".to_owned(),
+            },
+        ]
+    );
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::meal;
+use neis_client::types::MealServiceItem;
+use neis_client::watermark::{Incremental, MemoryWatermarks};
+use serde_json::json;
+
+const MEALS: &str = "mealServiceDietInfo";
+const QUERY: &str = "ATPT_OFCDC_SC_CODE=B10&SD_SCHUL_CODE=7010959";
+
+fn item(ymd: &str, load_dtm: &str) -> MealServiceItem {
+    meal(json!({"MLSV_YMD": ymd, "LOAD_DTM": load_dtm}))
+}
+
+#[test]
+fn filter_returns_rows_modified_after_the_committed_watermark() {
+    let mut sync = Incremental::new(MemoryWatermarks::new());
+
+    let first = vec![item("20250303", "20250301"), item("20250304", "20250301")];
+    let changed = sync.filter(MEALS, QUERY, first).unwrap();
+    assert_eq!(changed.len(), 2);
+
+    // 저장하지 못했으면 commit 하지 않아 다음에 다시 받는다
+    let again = vec![item("20250303", "20250301")];
+    let changed = sync.filter(MEALS, QUERY, again).unwrap();
+    assert_eq!(changed.len(), 1);
+    sync.commit().unwrap();
+
+    let second = vec![
+        item("20250303", "20250301"),
+        item("20250304", "20250305120000"),
+        item("20250305", "20250305120000"),
+    ];
+    let changed = sync.filter(MEALS, QUERY, second).unwrap();
+    assert_eq!(changed.len(), 2);
+}
+
+#[test]
+fn watermarks_are_kept_per_query() {
+    let mut sync = Incremental::new(MemoryWatermarks::new());
+    sync.filter(MEALS, QUERY, vec![item("20250303", "20250305")])
+        .unwrap();
+    sync.commit().unwrap();
+
+    let other = format!("{}&MLSV_FROM_YMD=20250201&MLSV_TO_YMD=20250228", QUERY);
+    let changed = sync
+        .filter(MEALS, &other, vec![item("20250203", "20250201")])
+        .unwrap();
+    assert_eq!(changed.len(), 1);
+}
+
+#[test]
+fn rollback_keeps_the_watermark() {
+    let mut sync = Incremental::new(MemoryWatermarks::new());
+    sync.filter(MEALS, QUERY, vec![item("20250303", "20250301")])
+        .unwrap();
+    sync.rollback();
+    sync.commit().unwrap();
+
+    let changed = sync
+        .filter(MEALS, QUERY, vec![item("20250303", "20250301")])
+        .unwrap();
+    assert_eq!(changed.len(), 1);
+}